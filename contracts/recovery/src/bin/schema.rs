@@ -0,0 +1,12 @@
+use cosmwasm_schema::write_api;
+
+use recovery::msg::{SudoMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        migrate: MigrateMsg,
+        sudo: SudoMsg,
+        query: QueryMsg,
+    }
+}
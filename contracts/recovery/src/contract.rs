@@ -0,0 +1,115 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use cw2::set_contract_version;
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+use crate::msg::{Any, CallInfo, InstantiateMsg, QueryMsg, RecoveryCredentials, SudoMsg};
+use crate::state::{GUARDIAN, NONCE};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:recovery";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Handling contract instantiation
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    GUARDIAN.save(deps.storage, &msg.guardian)?;
+    NONCE.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+/// Handling contract execution
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(
+    deps: DepsMut,
+    env: Env,
+    msg: SudoMsg
+) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::PreExecute{ msgs, call_info, is_authz }
+        => execute_pre_execute(deps,env,msgs,call_info,is_authz),
+
+        SudoMsg::AfterExecute{ msgs, call_info, is_authz }
+        => execute_after_execute(deps,env,msgs,call_info,is_authz),
+
+        SudoMsg::Recover{ caller, pub_key, credentials }
+        => execute_recover(deps,env,caller,pub_key,credentials),
+    }
+}
+
+/// Bytes the guardian signs to allow rotating the key of `account` to `pub_key`.
+/// Including the nonce makes each credentials usable only once.
+pub fn recovery_payload(account: &str, pub_key: &[u8], nonce: u64) -> Vec<u8> {
+    format!("recover {} {} {}", account, nonce, Binary::from(pub_key).to_base64()).into_bytes()
+}
+
+fn execute_recover(
+    deps: DepsMut,
+    env: Env,
+    caller: String,
+    pub_key: Binary,
+    credentials: Binary,
+) -> Result<Response, ContractError> {
+    let credentials: RecoveryCredentials =
+        from_json(&credentials).map_err(|_| ContractError::InvalidCredentials {})?;
+
+    let guardian = GUARDIAN.load(deps.storage)?;
+    let nonce = NONCE.load(deps.storage)?;
+
+    // secp256k1 signatures are made over the sha256 digest of the payload
+    let payload = recovery_payload(env.contract.address.as_str(), &pub_key, nonce);
+    let hash = Sha256::digest(payload);
+    if !deps.api.secp256k1_verify(&hash, &credentials.signature, &guardian)? {
+        return Err(ContractError::InvalidCredentials {})
+    }
+
+    NONCE.save(deps.storage, &(nonce + 1))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "recover")
+        .add_attribute("caller", caller)
+        .add_attribute("nonce", nonce.to_string()))
+}
+
+fn execute_pre_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _msgs: Vec<Any>,
+    _call_info: CallInfo,
+    _is_authz: bool,
+) -> Result<Response, ContractError> {
+    Ok(Response::new().add_attribute("action", "pre_execute"))
+}
+
+fn execute_after_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _msgs: Vec<Any>,
+    _call_info: CallInfo,
+    _is_authz: bool
+) -> Result<Response, ContractError> {
+    Ok(Response::new().add_attribute("action", "after_execute"))
+}
+
+/// Handling contract query
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Guardian {} => to_json_binary(&GUARDIAN.load(deps.storage)?),
+        QueryMsg::Nonce {} => to_json_binary(&NONCE.load(deps.storage)?),
+    }
+}
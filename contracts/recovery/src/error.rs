@@ -0,0 +1,14 @@
+use cosmwasm_std::{StdError, VerificationError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Verification(#[from] VerificationError),
+
+    #[error("invalid recovery credentials")]
+    InvalidCredentials {},
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod unit_tests {
+    use cosmos_sdk_proto::cosmos::crypto::secp256k1::PubKey;
+    use cosmos_sdk_proto::traits::MessageExt;
+    use cosmwasm_std::{coins, Binary};
+    use crate::msg::{InstantiateMsg, QueryMsg};
+    use aura_test_tube::{ArtifactRegistry, Auth, AuthAccount, AuraTestApp, Bank, SmartAccount, Wasm};
+    use aura_test_tube::init_local_smart_account;
+    use aura_test_tube::{Module, Account};
+
+    #[test]
+    fn test_recover() {
+        let app = AuraTestApp::default();
+        let bank = Bank::new(&app);
+        let wasm = Wasm::new(&app);
+        let smartaccount = SmartAccount::new(&app);
+
+        let accs = app.init_base_accounts(&coins(100_000_000_000, "uaura"), 3).unwrap();
+        let (owner, guardian, new_owner) = (&accs[0], &accs[1], &accs[2]);
+
+        let code_id = ArtifactRegistry::new(&app).unwrap().store_code("recovery", owner).unwrap();
+        let init_msg = InstantiateMsg {
+            guardian: Binary::from(guardian.public_key().to_bytes()),
+        };
+        let (sa_acc, sa_addr) = smartaccount
+            .create(code_id, &init_msg, b"recovery", owner, &coins(10_000_000, "uaura"))
+            .unwrap();
+
+        let nonce: u64 = wasm.query(&sa_addr, &QueryMsg::Nonce {}).unwrap();
+        assert_eq!(nonce, 0);
+
+        // only the guardian can sign credentials
+        assert!(smartaccount
+            .recover_with_guardian(&sa_addr, &new_owner.public_key(), nonce, owner)
+            .is_err());
+
+        smartaccount
+            .recover_with_guardian(&sa_addr, &new_owner.public_key(), nonce, guardian)
+            .unwrap();
+
+        // the account key has been rotated
+        match Auth::new(&app).account(&sa_addr).unwrap() {
+            AuthAccount::Smart(account) => {
+                let expected = PubKey { key: new_owner.public_key().to_bytes() }.to_bytes().unwrap();
+                assert_eq!(account.pub_key.unwrap().value, expected);
+            }
+            other => panic!("expected a smart account, got {:?}", other),
+        }
+
+        // the old key can no longer sign
+        assert!(bank.send_coins(&owner.address(), &coins(100, "uaura"), &sa_acc).is_err());
+
+        let new_acc = init_local_smart_account(sa_addr.clone(), new_owner.private_key())
+            .unwrap()
+            .with_fee_setting(app.default_fee_setting().clone());
+        bank.send_coins(&owner.address(), &coins(100, "uaura"), &new_acc).unwrap();
+
+        // credentials can't be replayed
+        let nonce: u64 = wasm.query(&sa_addr, &QueryMsg::Nonce {}).unwrap();
+        assert_eq!(nonce, 1);
+        assert!(smartaccount
+            .recover_with_guardian(&sa_addr, &new_owner.public_key(), 0, guardian)
+            .is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Coin};
+
+/// Message type for `instantiate` entry_point
+#[cw_serde]
+pub struct InstantiateMsg {
+    // compressed secp256k1 public key of the guardian
+    pub guardian: Binary,
+}
+
+// A data structure representing an account's message
+// will be passed into the call to the smart-account contract every time tx arrives
+#[cw_serde]
+pub struct Any {
+    pub type_url: String, // url type of message
+    pub value:    Binary, // value of message
+}
+
+#[cw_serde]
+pub struct CallInfo {
+    pub fee: Vec<Coin>,
+    pub gas: u64,
+    pub fee_payer: String,
+    pub fee_granter: String,
+}
+
+/// Message type for `sudo` entry_point
+#[cw_serde]
+pub enum SudoMsg {
+    // required `PreExecute` method
+    PreExecute{
+        //list of messages in transaction
+        msgs: Vec<Any>,
+        call_info: CallInfo,
+        is_authz: bool
+    },
+
+    // required `AfterExecute` method
+    AfterExecute{
+        //list of messages in transaction
+        msgs: Vec<Any>,
+        call_info: CallInfo,
+        is_authz: bool
+    },
+
+    // called by `MsgRecover`, the account public key is replaced by `pub_key` on success
+    Recover{
+        caller: String,
+        pub_key: Binary,
+        // json encoded `RecoveryCredentials`
+        credentials: Binary,
+    },
+}
+
+// credentials expected by `Recover`
+#[cw_serde]
+pub struct RecoveryCredentials {
+    // guardian signature of `recovery_payload`
+    pub signature: Binary,
+}
+
+/// Message type for `migrate` entry_point
+#[cw_serde]
+pub enum MigrateMsg {}
+
+/// Message type for `query` entry_point
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Binary)]
+    Guardian {},
+
+    // nonce the next recovery credentials must be signed with
+    #[returns(u64)]
+    Nonce {},
+}
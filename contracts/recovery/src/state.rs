@@ -0,0 +1,8 @@
+use cosmwasm_std::Binary;
+use cw_storage_plus::Item;
+
+// secp256k1 public key of the guardian allowed to rotate the account key
+pub const GUARDIAN: Item<Binary> = Item::new("guardian");
+
+// incremented on every recovery, so that credentials can't be replayed
+pub const NONCE: Item<u64> = Item::new("nonce");
@@ -1,57 +1,22 @@
 #[cfg(test)]
 mod unit_tests {
     use std::option::Option::None;
-    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
     use cosmos_sdk_proto::traits::MessageExt;
-    use cosmos_sdk_proto::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
     use cosmwasm_schema::cw_serde;
-    use aura_test_tube::RunnerExecuteResult;
-    use aura_proto::types::smartaccount::v1beta1::{Params, CodeID};
-    use cosmwasm_std::coins;
-    use aura_test_tube::{Wasm, AuraTestApp, SmartAccount};
-    use cosmos_sdk_proto::cosmos::bank::v1beta1::{
-        QueryAllBalancesRequest, QueryAllBalancesResponse
-    };
+    use aura_proto::types::smartaccount::v1beta1::{Params, CodeId};
+    use cosmwasm_std::{coins, Empty, Uint128};
+    use crate::msg::{CallInfo, InstantiateMsg, SudoMsg};
+    use crate::state::{BALANCES, LIMITS};
+    use aura_test_tube::{assert_event, assert_no_event, ArtifactRegistry, Auth, AuthAccount, Authz, Bank, FeeGrant, Wasm, Contract, AuraTestApp, SmartAccount};
     use aura_test_tube::init_local_smart_account;
-    use aura_test_tube::{Runner, Module, Account, SigningAccount};
-
-    fn get_account_balances(app: &AuraTestApp, address: String, denom: &str) -> u128 {
-        let acc_balance = app.query::<QueryAllBalancesRequest,QueryAllBalancesResponse>(
-            "/cosmos.bank.v1beta1.Query/AllBalances",
-            &QueryAllBalancesRequest {
-                address,
-                pagination: None,
-            },
-        )
-        .unwrap()
-        .balances
-        .into_iter()
-        .find(|c| c.denom == denom)
-        .unwrap()
-        .amount
-        .parse::<u128>()
-        .unwrap();
-        return acc_balance;
-    }
-
-    fn send_coin(app: &AuraTestApp, from: &SigningAccount, to: String, amounts: Vec<Coin>) -> RunnerExecuteResult<MsgSendResponse> {
-        app.execute(
-            MsgSend {
-                from_address: from.address(),
-                to_address: to,
-                amount: amounts,
-            }, 
-            "/cosmos.bank.v1beta1.MsgSend", 
-            from
-        )
-    }
+    use aura_test_tube::{Module, Account};
 
     #[cw_serde]
     struct EmptyInit {}
 
     #[cw_serde]
-    struct Listen {
-        listen: EmptyInit
+    enum ListenerExecuteMsg {
+        Listen {},
     }
 
     #[test]
@@ -60,42 +25,32 @@ mod unit_tests {
         // id: aura-testnet
         // denom: uaura
         let app = AuraTestApp::default();
+        let bank = Bank::new(&app);
 
         let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
-        let acc_balance = get_account_balances(&app, acc.address(), "uaura");
+        let acc_balance = bank.balance(&acc.address(), "uaura").unwrap().u128();
         assert_eq!(acc_balance, 100_000_000_000u128);
         
         let wasm = Wasm::new(&app);
         let smartaccount = SmartAccount::new(&app);
         
-        let test_code = std::fs::read("../../artifacts/spend_limit.wasm").unwrap(); // load contract wasm 
+        // load contract wasm, checked against artifacts/checksums.txt
+        let artifacts = ArtifactRegistry::new(&app).unwrap();
 
         // store wasm for smartaccount initialization
-        let test_code_id = wasm
-            .store_code(
-                &test_code, 
-                None, 
-                &acc  
-            )
-            .unwrap()
-            .data
-            .code_id; 
+        let test_code_id = artifacts.store_code("spend-limit", &acc).unwrap();
         assert_eq!(test_code_id, 1);
         
         // set whitelist for code id, don't need government
         let params = Params {
-            whitelist_code_id: vec![CodeID{
+            whitelist_code_id: vec![CodeId{
                 code_id: test_code_id,
                 status: true
             }],
             disable_msgs_list: vec![],
             max_gas_execute: 2000000,
         };
-        let param_set = aura_proto::shim::Any{
-            type_url: String::from("/aura.smartaccount.v1beta1.Params"),
-            value: params.to_bytes().unwrap()
-        };
-        let _ = app.set_param_set("smartaccount", param_set.into()).unwrap();
+        let _ = app.set_param_set("smartaccount", params.to_any().into()).unwrap();
         // query smartaccount module param set
         let sa_params = smartaccount.query_params().unwrap();
         assert_eq!(sa_params.params, Some(params));
@@ -120,15 +75,9 @@ mod unit_tests {
             pub_key.clone()
         ).unwrap();
 
-        // send coin to smartaccount
-        let banksend_res: RunnerExecuteResult<MsgSendResponse> = send_coin(
-            &app,
-        &acc,
-        sa_addr.clone(),
-        vec![Coin{denom: "uaura".to_string(),amount: "10000000".to_string(),}]
-        );       
-        assert!(banksend_res.is_ok());
-        let acc_balance = get_account_balances(&app, sa_addr.clone(), "uaura");
+        // fund smartaccount
+        app.fund_account(&sa_addr, &coins(10_000_000, "uaura")).unwrap();
+        let acc_balance = bank.balance(&sa_addr, "uaura").unwrap().u128();
         assert_eq!(acc_balance, 10000000u128);
         
         // local account which has not been initialized on-chain
@@ -142,67 +91,77 @@ mod unit_tests {
             test_code_id, 
             salt, 
             init_msg, 
-            pub_key, 
+            pub_key.clone(), 
             &sa_acc,
         ).unwrap();
 
+        // the generated address is now a smart account holding our public key
+        match Auth::new(&app).account(&sa_addr).unwrap() {
+            AuthAccount::Smart(account) => {
+                assert_eq!(account.address, sa_addr);
+                assert_eq!(account.pub_key, Some(pub_key));
+            }
+            other => panic!("expected a smart account, got {:?}", other),
+        }
+
         let acc2 = app.init_base_account(&coins(10, "uaura")).unwrap();
 
-        let banksend_res: RunnerExecuteResult<MsgSendResponse> = send_coin(
-            &app,
-        &sa_acc,
-        acc2.address(),
-        vec![Coin{denom: "uaura".to_string(),amount: "5000".to_string(),}]
-        );       
+        let banksend_res = bank.send_coins(&acc2.address(), &coins(5000, "uaura"), &sa_acc);
         println!("{:?}", banksend_res);
         assert!(banksend_res.is_ok());
 
+        // the chain called the account hooks around the send
+        let hooks = app.hook_invocations().unwrap();
+        assert_eq!(hooks.len(), 2);
+        assert!(hooks[0].is_pre_execute() && hooks[1].is_after_execute());
+        for hook in hooks {
+            assert_eq!(hook.contract, sa_addr);
+            assert_eq!(hook.msgs[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+            assert_eq!(hook.call_info.fee_payer, sa_addr);
+            assert!(!hook.is_authz);
+            assert!(hook.is_ok());
+        }
+
         // send coin from smartaccount success
-        let acc_balance = get_account_balances(&app, acc2.address(), "uaura");
+        let acc_balance = bank.balance(&acc2.address(), "uaura").unwrap().u128();
         assert_eq!(acc_balance, 5010u128);
 
-        let banksend_res: RunnerExecuteResult<MsgSendResponse> = send_coin(
-            &app,
-        &sa_acc,
-        acc2.address(),
-        vec![Coin{denom: "uaura".to_string(),amount: "5001".to_string(),}]
-        );       
+        // internal state without query endpoint
+        let pre_balances = wasm.query_item(&sa_addr, &BALANCES).unwrap().unwrap();
+        assert_eq!(pre_balances.len(), 1);
+        let limit = wasm.query_map_entry(&sa_addr, &LIMITS, "uaura".to_string()).unwrap().unwrap();
+        assert_eq!(limit.used, Uint128::new(5000));
+        let version = wasm.query_contract_version(&sa_addr).unwrap();
+        assert_eq!(version.contract, "crates.io:spend-limit");
+
+        let banksend_res = bank.send_coins(&acc2.address(), &coins(5001, "uaura"), &sa_acc);
         assert!(banksend_res.is_err());
 
+        // the tx failed, its hooks are still traced
+        let after_execute = app.hook_invocations().unwrap().pop().unwrap();
+        assert!(after_execute.is_after_execute());
+        assert!(after_execute.error.unwrap().contains("limit exceed for denom: uaura"));
+
         // send coin from smartaccount fail, reach spend-limit 
-        let acc_balance = get_account_balances(&app, acc2.address(), "uaura");
+        let acc_balance = bank.balance(&acc2.address(), "uaura").unwrap().u128();
         assert_eq!(acc_balance, 5010u128);
 
         _ = app.skip_time(3600); // skip 1hours
 
         // re-send after 1hours
-        let banksend_res: RunnerExecuteResult<MsgSendResponse> = send_coin(
-            &app,
-        &sa_acc,
-        acc2.address(),
-        vec![Coin{denom: "uaura".to_string(),amount: "5001".to_string(),}]
-        );       
+        let banksend_res = bank.send_coins(&acc2.address(), &coins(5001, "uaura"), &sa_acc);
         assert!(banksend_res.is_ok());
 
         // send coin from smartaccount success, spend-limit outdated
-        let acc_balance = get_account_balances(&app, acc2.address(), "uaura");
+        let acc_balance = bank.balance(&acc2.address(), "uaura").unwrap().u128();
         assert_eq!(acc_balance, 10011u128);
 
 
-        let listener_code = std::fs::read("../../artifacts/listener.wasm").unwrap(); // load contract wasm 
-
-        let listener_code_id = wasm
-            .store_code(
-                &listener_code, 
-                None, 
-                &acc  
-            )
-            .unwrap()
-            .data
-            .code_id; 
+        let listener_code_id = artifacts.store_code("listener", &acc).unwrap();
         assert_eq!(listener_code_id, 2);
 
-        let wasm_instantiate = wasm.instantiate(
+        let listener: Contract<_, ListenerExecuteMsg, Empty> = Contract::instantiate(
+            &wasm,
             listener_code_id,
             &EmptyInit{},
             None,
@@ -212,8 +171,87 @@ mod unit_tests {
         ).unwrap();
 
         // use smartaccount to execute contract
-        let _ = wasm.execute(&wasm_instantiate.data.address, &Listen{
-            listen: EmptyInit{}
-        }, &[], &sa_acc).unwrap();
+        let _ = listener.execute(&ListenerExecuteMsg::Listen {}, &[], &sa_acc).unwrap();
+        assert!(listener.balance().unwrap().is_empty());
+
+        // smartaccount as authz grantee, hooks are called with is_authz set
+        let authz = Authz::new(&app);
+        authz.grant_generic(&sa_addr, "/cosmos.bank.v1beta1.MsgSend", &acc).unwrap();
+        let send = cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend {
+            from_address: acc.address(),
+            to_address: acc2.address(),
+            amount: vec![cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                denom: "uaura".to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+        authz.exec_msgs(vec![cosmos_sdk_proto::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: send.to_bytes().unwrap(),
+        }], &sa_acc).unwrap();
+        assert_eq!(bank.balance(&acc2.address(), "uaura").unwrap().u128(), 10111u128);
+
+        // sponsored tx, fees are paid by the granter and not counted by spend-limit
+        FeeGrant::new(&app).grant_basic_allowance(&sa_addr, &[], &acc).unwrap();
+        let sa_acc = sa_acc.with_fee_granter(&acc.address());
+        let sa_balance = bank.balance(&sa_addr, "uaura").unwrap().u128();
+        bank.send_coins(&acc2.address(), &coins(100, "uaura"), &sa_acc).unwrap();
+        assert_eq!(bank.balance(&sa_addr, "uaura").unwrap().u128(), sa_balance - 100);
+    }
+    #[test]
+    fn test_spend_limit_hooks() {
+        let app = AuraTestApp::default();
+        let wasm = Wasm::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        let code_id = ArtifactRegistry::new(&app).unwrap().store_code("spend-limit", &acc).unwrap();
+        let contract = wasm.instantiate(
+            code_id,
+            &InstantiateMsg { limit: cosmwasm_std::coin(10000, "uaura") },
+            None,
+            Some("spend-limit"),
+            &[],
+            &acc,
+        ).unwrap().data.address;
+        app.fund_account(&contract, &coins(20000, "uaura")).unwrap();
+
+        // hooks are called with the tx messages, craft them directly
+        let msgs = vec![crate::msg::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: cosmwasm_std::to_binary(&crate::msg::MsgSend {
+                from_address: contract.clone(),
+                to_address: acc.address(),
+                amount: coins(6000, "uaura"),
+            }).unwrap(),
+        }];
+        let call_info = CallInfo {
+            fee: vec![],
+            gas: 200000,
+            fee_payer: contract.clone(),
+            fee_granter: String::new(),
+        };
+        let pre_execute = SudoMsg::PreExecute { msgs: msgs.clone(), call_info: call_info.clone(), is_authz: false };
+        let after_execute = SudoMsg::AfterExecute { msgs, call_info, is_authz: false };
+
+        let res = wasm.sudo(&contract, &pre_execute).unwrap();
+        assert_event!(res, "wasm", "_contract_address" => contract, "action" => "pre_execute");
+        assert_no_event!(res, "wasm", "action" => "after_execute");
+        assert_eq!(res.wasm_events(&contract).len(), 1);
+        assert_eq!(wasm.query_item(&contract, &BALANCES).unwrap().unwrap(), coins(20000, "uaura"));
+
+        // simulate the tx spending 6000uaura between the hooks
+        app.set_balance(&contract, &coins(14000, "uaura")).unwrap();
+        let res = wasm.sudo(&contract, &after_execute).unwrap();
+        assert_eq!(res.attribute_values("wasm", "action"), vec!["after_execute"]);
+        let limit = wasm.query_map_entry(&contract, &LIMITS, "uaura".to_string()).unwrap().unwrap();
+        assert_eq!(limit.used, Uint128::new(6000));
+
+        // spending another 6000uaura exceeds the limit, and the failed call leaves no state behind
+        wasm.sudo(&contract, &pre_execute).unwrap();
+        app.set_balance(&contract, &coins(8000, "uaura")).unwrap();
+        let err = wasm.sudo(&contract, &after_execute).unwrap_err();
+        assert!(err.to_string().contains("limit exceed for denom: uaura"));
+        let limit = wasm.query_map_entry(&contract, &LIMITS, "uaura".to_string()).unwrap().unwrap();
+        assert_eq!(limit.used, Uint128::new(6000));
     }
 }
\ No newline at end of file
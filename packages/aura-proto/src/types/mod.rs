@@ -1 +1,2 @@
-pub mod smartaccount;
\ No newline at end of file
+//! Generated by `proto-build` from the protos in `packages/proto-build/proto`, do not edit.
+pub mod smartaccount;
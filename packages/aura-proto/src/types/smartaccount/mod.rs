@@ -1 +1,2 @@
-pub mod v1beta1;
\ No newline at end of file
+//! Generated by `proto-build` from the protos in `packages/proto-build/proto`, do not edit.
+pub mod v1beta1;
@@ -1,6 +1,6 @@
+//! Generated by `proto-build` from the protos in `packages/proto-build/proto`, do not edit.
 use aura_proto_derive::CosmwasmExt;
-
-/// Params defines the parameters for the smartaccount module.
+/// SmartAccount is the auth account type of an activated smart account.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -12,19 +12,25 @@ use aura_proto_derive::CosmwasmExt;
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/cosmos.smartaccount.v1beta1.CodeID")]
-pub struct CodeID {
-    #[prost(uint64, tag = "1")]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.SmartAccount")]
+pub struct SmartAccount {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub pub_key: ::core::option::Option<crate::shim::Any>,
+    #[prost(uint64, tag = "3")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
-    pub code_id: u64,
-
-    #[prost(bool, tag = "2")]
-    pub status: bool,
+    pub account_number: u64,
+    #[prost(uint64, tag = "4")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub sequence: u64,
 }
-
 /// Params defines the parameters for the smartaccount module.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
@@ -37,14 +43,12 @@ pub struct CodeID {
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/cosmos.smartaccount.v1beta1.Params")]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.Params")]
 pub struct Params {
     #[prost(message, repeated, tag = "1")]
-    pub whitelist_code_id: ::prost::alloc::vec::Vec<CodeID>,
-
+    pub whitelist_code_id: ::prost::alloc::vec::Vec<CodeId>,
     #[prost(string, repeated, tag = "2")]
     pub disable_msgs_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-
     #[prost(uint64, tag = "3")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
@@ -52,7 +56,7 @@ pub struct Params {
     )]
     pub max_gas_execute: u64,
 }
-
+/// CodeID is a wasm code allowed, or not, to back smart accounts.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -64,33 +68,42 @@ pub struct Params {
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgActivateAccount")]
-pub struct MsgActivateAccountRequest {
-    #[prost(string, tag = "1")]
-    pub account_address: ::prost::alloc::string::String,
-
-    #[prost(bytes = "vec", tag = "2")]
-    pub salt: ::prost::alloc::vec::Vec<u8>,
-
-    #[prost(uint64, tag = "3")]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.CodeID")]
+pub struct CodeId {
+    #[prost(uint64, tag = "1")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub code_id: u64,
-
-    #[prost(message, optional, tag = "4")]
-    pub pub_key: ::core::option::Option<crate::shim::Any>,
-
-    #[prost(bytes = "vec", tag = "5")]
-    pub init_msg: ::prost::alloc::vec::Vec<u8>
+    #[prost(bool, tag = "2")]
+    pub status: bool,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct MsgActivateAccountResponse {
-    #[prost(string, tag = "1")]
-    pub address: ::prost::alloc::string::String,
+#[deprecated(note = "renamed to `CodeId`")]
+pub type CodeID = CodeId;
+/// GenesisState defines the smartaccount module's genesis state.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.GenesisState")]
+pub struct GenesisState {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<Params>,
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub smart_account_id: u64,
 }
-
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -102,23 +115,28 @@ pub struct MsgActivateAccountResponse {
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgRecover")]
-pub struct MsgRecoverRequest {
-    #[prost(string, tag = "1")]
-    pub creator: ::prost::alloc::string::String,
-
-    #[prost(string, tag = "2")]
-    pub address: ::prost::alloc::string::String,
-
-    #[prost(message, optional, tag = "3")]
-    pub public_key: ::core::option::Option<crate::shim::Any>,
-
-    #[prost(string, tag = "4")]
-    pub credentials: ::prost::alloc::string::String
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.QueryParamsRequest")]
+#[proto_query(
+    path = "/aura.smartaccount.v1beta1.Query/Params",
+    response_type = QueryParamsResponse
+)]
+pub struct QueryParamsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.QueryParamsResponse")]
+pub struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<Params>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct MsgRecoverResponse {}
-
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -142,15 +160,12 @@ pub struct QueryGenerateAccountRequest {
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub code_id: u64,
-
     #[prost(bytes = "vec", tag = "2")]
     pub salt: ::prost::alloc::vec::Vec<u8>,
-
     #[prost(bytes = "vec", tag = "3")]
     pub init_msg: ::prost::alloc::vec::Vec<u8>,
-
     #[prost(message, optional, tag = "4")]
-    pub public_key: ::core::option::Option<crate::shim::Any>
+    pub public_key: ::core::option::Option<crate::shim::Any>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
@@ -168,7 +183,6 @@ pub struct QueryGenerateAccountResponse {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -180,12 +194,41 @@ pub struct QueryGenerateAccountResponse {
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/aura.smartaccount.v1beta1.QueryParamsRequest")]
-#[proto_query(
-    path = "/aura.smartaccount.v1beta1.Query/Params",
-    response_type = QueryParamsResponse
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgActivateAccount")]
+pub struct MsgActivateAccount {
+    #[prost(string, tag = "1")]
+    pub account_address: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub code_id: u64,
+    #[prost(message, optional, tag = "4")]
+    pub pub_key: ::core::option::Option<crate::shim::Any>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub init_msg: ::prost::alloc::vec::Vec<u8>,
+}
+#[deprecated(note = "renamed to `MsgActivateAccount`")]
+pub type MsgActivateAccountRequest = MsgActivateAccount;
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema,
+    CosmwasmExt,
 )]
-pub struct QueryParamsRequest {}
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgActivateAccountResponse")]
+pub struct MsgActivateAccountResponse {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(
     Clone,
@@ -197,8 +240,58 @@ pub struct QueryParamsRequest {}
     ::schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/aura.smartaccount.v1beta1.QueryParamsResponse")]
-pub struct QueryParamsResponse {
-    #[prost(message, optional, tag = "1")]
-    pub params: ::core::option::Option<Params>,
-}
\ No newline at end of file
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgRecover")]
+pub struct MsgRecover {
+    #[prost(string, tag = "1")]
+    pub creator: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub public_key: ::core::option::Option<crate::shim::Any>,
+    #[prost(string, tag = "4")]
+    pub credentials: ::prost::alloc::string::String,
+}
+#[deprecated(note = "renamed to `MsgRecover`")]
+pub type MsgRecoverRequest = MsgRecover;
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    ::serde::Serialize,
+    ::serde::Deserialize,
+    ::schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/aura.smartaccount.v1beta1.MsgRecoverResponse")]
+pub struct MsgRecoverResponse {}
+/// Typed queries to the `aura.smartaccount.v1beta1` module, sent as stargate queries.
+pub struct SmartaccountQuerier<'a, Q: cosmwasm_std::CustomQuery> {
+    querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
+}
+impl<'a, Q: cosmwasm_std::CustomQuery> SmartaccountQuerier<'a, Q> {
+    pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
+        Self { querier }
+    }
+    pub fn params(
+        &self,
+    ) -> std::result::Result<QueryParamsResponse, cosmwasm_std::StdError> {
+        QueryParamsRequest {}.query(self.querier)
+    }
+    pub fn generate_account(
+        &self,
+        code_id: u64,
+        salt: ::prost::alloc::vec::Vec<u8>,
+        init_msg: ::prost::alloc::vec::Vec<u8>,
+        public_key: ::core::option::Option<crate::shim::Any>,
+    ) -> std::result::Result<QueryGenerateAccountResponse, cosmwasm_std::StdError> {
+        QueryGenerateAccountRequest {
+            code_id,
+            salt,
+            init_msg,
+            public_key,
+        }
+            .query(self.querier)
+    }
+}
@@ -11,7 +11,10 @@ import (
 	"time"
 
 	// helpers
+	"github.com/cosmos/cosmos-sdk/baseapp"
 	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	"github.com/cosmos/cosmos-sdk/crypto/hd"
+	"github.com/cosmos/cosmos-sdk/store/prefix"
 	"github.com/golang/protobuf/proto"
 	"github.com/pkg/errors"
 
@@ -22,9 +25,12 @@ import (
 
 	// cosmos sdk
 	sdk "github.com/cosmos/cosmos-sdk/types"
+	authtypes "github.com/cosmos/cosmos-sdk/x/auth/types"
+	govtypes "github.com/cosmos/cosmos-sdk/x/gov/types"
 	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
 
 	// wasmd
+	wasmkeeper "github.com/CosmWasm/wasmd/x/wasm/keeper"
 	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
 
 	// cosmwasm-testing
@@ -57,6 +63,7 @@ func InitTestEnv() uint64 {
 	env.ParamTypesRegistry = *testenv.NewParamTypeRegistry()
 
 	env.SetupParamTypes()
+	env.SudoTracer = testenv.InstallSudoTracer(env.App)
 
 	// Allow testing unoptimized contract
 	wasmtypes.MaxWasmSize = 1024 * 1024 * 1024 * 1024 * 1024
@@ -103,6 +110,91 @@ func InitAccount(envId uint64, coinsJson string) *C.char {
 	return C.CString(base64Priv)
 }
 
+//export InitAccountFromMnemonic
+func InitAccountFromMnemonic(envId uint64, mnemonic, hdPath, coinsJson string) *C.char {
+	env := loadEnv(envId)
+	var coins sdk.Coins
+
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		panic(err)
+	}
+
+	derivedPriv, err := hd.Secp256k1.Derive()(mnemonic, "", hdPath)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	priv := hd.Secp256k1.Generate()(derivedPriv)
+	accAddr := sdk.AccAddress(priv.PubKey().Address())
+
+	err = testenv.FundAccount(env.App.BankKeeper, env.Ctx, accAddr, coins)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrapf(err, "Failed to fund account"))
+	}
+
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes(priv.Bytes())
+}
+
+//export InitAccountFromPrivKey
+func InitAccountFromPrivKey(envId uint64, base64PrivKey, coinsJson string) *C.char {
+	env := loadEnv(envId)
+	var coins sdk.Coins
+
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		panic(err)
+	}
+
+	privBytes, err := base64.StdEncoding.DecodeString(base64PrivKey)
+	if err != nil {
+		panic(err)
+	}
+
+	if len(privBytes) != secp256k1.PrivKeySize {
+		err := fmt.Errorf("invalid private key length: expected %d, got %d", secp256k1.PrivKeySize, len(privBytes))
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	priv := secp256k1.PrivKey(privBytes)
+	accAddr := sdk.AccAddress(priv.PubKey().Address())
+
+	err = testenv.FundAccount(env.App.BankKeeper, env.Ctx, accAddr, coins)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrapf(err, "Failed to fund account"))
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export FundAccount
+func FundAccount(envId uint64, bech32Address, coinsJson string) *C.char {
+	env := loadEnv(envId)
+	var coins sdk.Coins
+
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		panic(err)
+	}
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	err = testenv.FundAddress(env.App.BankKeeper, env.Ctx, addr, coins)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrapf(err, "Failed to fund account"))
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
 //export BeginBlock
 func BeginBlock(envId uint64) {
 	env := loadEnv(envId)
@@ -147,6 +239,33 @@ func Execute(envId uint64, base64ReqDeliverTx string) *C.char {
 		return encodeErrToResultBytes(result.ExecuteError, err)
 	}
 
+	// only keep the hooks called by this tx
+	env.SudoTracer.Reset()
+
+	// min gas prices are only checked by the ante handler in CheckTx mode,
+	// so run it first to reject underpaid txs like a validator node would
+	if env.EnforceMinGasPrices {
+		resCheckTx := env.App.CheckTx(abci.RequestCheckTx{Tx: reqDeliverTx.Tx, Type: abci.CheckTxType_New})
+		if resCheckTx.IsErr() {
+			bz, err := proto.Marshal(&abci.ResponseDeliverTx{
+				Code:      resCheckTx.Code,
+				Log:       resCheckTx.Log,
+				GasWanted: resCheckTx.GasWanted,
+				GasUsed:   resCheckTx.GasUsed,
+				Events:    resCheckTx.Events,
+				Codespace: resCheckTx.Codespace,
+			})
+			if err != nil {
+				panic(err)
+			}
+
+			return encodeBytesResultBytes(bz)
+		}
+	}
+
+	// hooks of a passing CheckTx run again in DeliverTx
+	env.SudoTracer.Reset()
+
 	resDeliverTx := env.App.DeliverTx(reqDeliverTx)
 	bz, err := proto.Marshal(&resDeliverTx)
 
@@ -313,6 +432,230 @@ func GetParamSet(envId uint64, subspaceName, typeUrl string) *C.char {
 	return encodeBytesResultBytes(bz)
 }
 
+//export SetMinGasPrices
+func SetMinGasPrices(envId uint64, minGasPrices string) *C.char {
+	env := loadEnv(envId)
+
+	// Temp fix for concurrency issue
+	mu.Lock()
+	defer mu.Unlock()
+
+	gasPrices, err := sdk.ParseDecCoins(minGasPrices)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	baseapp.SetMinGasPrices(minGasPrices)(env.App.BaseApp)
+	env.EnforceMinGasPrices = !gasPrices.IsZero()
+
+	// the CheckTx context only takes the app's min gas prices on commit,
+	// so commit an empty block for the next tx to be checked against them
+	env.BeginNewBlock(false)
+	env.App.EndBlock(abci.RequestEndBlock{Height: env.Ctx.BlockHeight()})
+	env.App.Commit()
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+// ========= cheatcodes =========
+
+//export SetBalance
+func SetBalance(envId uint64, bech32Address, coinsJson string) *C.char {
+	env := loadEnv(envId)
+	var coins sdk.Coins
+
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		panic(err)
+	}
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	err = testenv.SetBalance(env.App.BankKeeper, env.Ctx, addr, coins)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrapf(err, "Failed to set balance"))
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export SetContractState
+func SetContractState(envId uint64, bech32Contract, base64Key, base64Value string) *C.char {
+	env := loadEnv(envId)
+
+	contractAddr, err := sdk.AccAddressFromBech32(bech32Contract)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	if !env.App.WasmKeeper.HasContractInfo(env.Ctx, contractAddr) {
+		err := errors.New("No contract found for `" + bech32Contract + "`")
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	key, err := base64.StdEncoding.DecodeString(base64Key)
+	if err != nil {
+		panic(err)
+	}
+
+	value, err := base64.StdEncoding.DecodeString(base64Value)
+	if err != nil {
+		panic(err)
+	}
+
+	storeKey := env.App.GetKey(wasmtypes.StoreKey)
+	contractStore := prefix.NewStore(env.Ctx.KVStore(storeKey), wasmtypes.GetContractStorePrefix(contractAddr))
+
+	// empty value removes the key, as the store does not allow empty values
+	if len(value) == 0 {
+		contractStore.Delete(key)
+	} else {
+		contractStore.Set(key, value)
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export SetAccountSequence
+func SetAccountSequence(envId uint64, bech32Address string, sequence uint64) *C.char {
+	env := loadEnv(envId)
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	acc := env.App.AccountKeeper.GetAccount(env.Ctx, addr)
+	if acc == nil {
+		return encodeErrToResultBytes(result.ExecuteError, fmt.Errorf("Account not found"))
+	}
+
+	if err := acc.SetSequence(sequence); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	env.App.AccountKeeper.SetAccount(env.Ctx, acc)
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export SetContractAdmin
+func SetContractAdmin(envId uint64, bech32Contract, bech32Admin string) *C.char {
+	env := loadEnv(envId)
+
+	contractAddr, err := sdk.AccAddressFromBech32(bech32Contract)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// gov permission allows modifying any contract regardless of the caller
+	contractKeeper := wasmkeeper.NewGovPermissionKeeper(env.App.WasmKeeper)
+	caller := authtypes.NewModuleAddress(govtypes.ModuleName)
+
+	if bech32Admin == "" {
+		err = contractKeeper.ClearContractAdmin(env.Ctx, contractAddr, caller)
+	} else {
+		adminAddr, addrErr := sdk.AccAddressFromBech32(bech32Admin)
+		if addrErr != nil {
+			return encodeErrToResultBytes(result.ExecuteError, addrErr)
+		}
+		err = contractKeeper.UpdateContractAdmin(env.Ctx, contractAddr, caller, adminAddr)
+	}
+
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export WasmSudo
+func WasmSudo(envId uint64, bech32Contract, base64Msg string) *C.char {
+	env := loadEnv(envId)
+	// Temp fix for concurrency issue
+	mu.Lock()
+	defer mu.Unlock()
+
+	contractAddr, err := sdk.AccAddressFromBech32(bech32Contract)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	msg, err := base64.StdEncoding.DecodeString(base64Msg)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// run in a cached context with a fresh event manager, so a failing
+	// sudo call leaves no state behind and only its own events are returned
+	ctx, write := env.Ctx.WithEventManager(sdk.NewEventManager()).CacheContext()
+	contractKeeper := wasmkeeper.NewGovPermissionKeeper(env.App.WasmKeeper)
+
+	data, err := contractKeeper.Sudo(ctx, contractAddr, msg)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	write()
+
+	// wrap the response like a DeliverTx of a single MsgSudoContract,
+	// so it can be decoded the same way as any other execute response
+	msgResponse, err := codectypes.NewAnyWithValue(&wasmtypes.MsgSudoContractResponse{Data: data})
+	if err != nil {
+		panic(err)
+	}
+
+	txMsgData, err := proto.Marshal(&sdk.TxMsgData{MsgResponses: []*codectypes.Any{msgResponse}})
+	if err != nil {
+		panic(err)
+	}
+
+	bz, err := proto.Marshal(&abci.ResponseDeliverTx{
+		Data:    txMsgData,
+		GasUsed: int64(ctx.GasMeter().GasConsumed()),
+		Events:  ctx.EventManager().ABCIEvents(),
+	})
+	if err != nil {
+		panic(err)
+	}
+
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes(bz)
+}
+
+//export SudoTraces
+func SudoTraces(envId uint64) *C.char {
+	env := loadEnv(envId)
+	// Temp fix for concurrency issue
+	mu.Lock()
+	defer mu.Unlock()
+
+	bz, err := json.Marshal(env.SudoTracer.Invocations())
+	if err != nil {
+		panic(err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
 // ========= utils =========
 
 func loadEnv(envId uint64) testenv.TestEnv {
@@ -26,6 +26,7 @@ import (
 	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
 	sdk "github.com/cosmos/cosmos-sdk/types"
 	bankkeeper "github.com/cosmos/cosmos-sdk/x/bank/keeper"
+	govtypes "github.com/cosmos/cosmos-sdk/x/gov/types"
 	minttypes "github.com/cosmos/cosmos-sdk/x/mint/types"
 	slashingtypes "github.com/cosmos/cosmos-sdk/x/slashing/types"
 	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
@@ -43,6 +44,10 @@ type TestEnv struct {
 	App                *app.App
 	Ctx                sdk.Context
 	ParamTypesRegistry ParamTypeRegistry
+	// EnforceMinGasPrices makes Execute run CheckTx before DeliverTx
+	EnforceMinGasPrices bool
+	// SudoTracer records the smart account hooks called by the last executed tx
+	SudoTracer *SudoTracer
 }
 
 const ChainID = "aura-testnet"
@@ -85,6 +90,40 @@ func FundAccount(bankKeeper bankkeeper.Keeper, ctx sdk.Context, addr sdk.AccAddr
 	return bankKeeper.SendCoinsFromModuleToAccount(ctx, minttypes.ModuleName, addr, amounts)
 }
 
+// FundAddress mints coins to any address. Unlike FundAccount it sends them with SendCoins,
+// bypassing the blocked address check, so module accounts can be funded too.
+func FundAddress(bankKeeper bankkeeper.Keeper, ctx sdk.Context, addr sdk.AccAddress, amounts sdk.Coins) error {
+	if err := bankKeeper.MintCoins(ctx, minttypes.ModuleName, amounts); err != nil {
+		return err
+	}
+
+	return bankKeeper.SendCoins(ctx, authtypes.NewModuleAddress(minttypes.ModuleName), addr, amounts)
+}
+
+// SetBalance mints or burns coins so that addr holds exactly the given amount
+// of each denom in amounts. Balances of other denoms are left untouched.
+func SetBalance(bankKeeper bankkeeper.Keeper, ctx sdk.Context, addr sdk.AccAddress, amounts sdk.Coins) error {
+	for _, target := range amounts {
+		current := bankKeeper.GetBalance(ctx, addr, target.Denom)
+
+		if current.Amount.LT(target.Amount) {
+			if err := FundAddress(bankKeeper, ctx, addr, sdk.NewCoins(target.Sub(current))); err != nil {
+				return err
+			}
+		} else if current.Amount.GT(target.Amount) {
+			excess := sdk.NewCoins(current.Sub(target))
+			if err := bankKeeper.SendCoinsFromAccountToModule(ctx, addr, govtypes.ModuleName, excess); err != nil {
+				return err
+			}
+			if err := bankKeeper.BurnCoins(ctx, govtypes.ModuleName, excess); err != nil {
+				return err
+			}
+		}
+	}
+
+	return nil
+}
+
 func SetupAuraApp() *app.App {
 	db := dbm.NewMemDB()
 	appObj := app.New(
@@ -0,0 +1,233 @@
+package testenv
+
+import (
+	"encoding/json"
+	"reflect"
+	"unsafe"
+
+	wasmvm "github.com/CosmWasm/wasmvm"
+	wasmvmtypes "github.com/CosmWasm/wasmvm/types"
+
+	wasmkeeper "github.com/CosmWasm/wasmd/x/wasm/keeper"
+	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
+
+	"github.com/aura-nw/aura/app"
+)
+
+// sudo messages sent by x/smartaccount to the account contract around each tx
+var smartAccountHooks = []string{"pre_execute", "after_execute"}
+
+// HookInvocation is a smart account hook called through sudo
+type HookInvocation struct {
+	Hook     string          `json:"hook"`
+	Contract string          `json:"contract"`
+	Msgs     json.RawMessage `json:"msgs"`
+	CallInfo json.RawMessage `json:"call_info"`
+	IsAuthz  bool            `json:"is_authz"`
+	GasUsed  uint64          `json:"gas_used"`
+	Response *HookResponse   `json:"response,omitempty"`
+	Error    string          `json:"error,omitempty"`
+}
+
+type HookResponse struct {
+	Data       []byte          `json:"data,omitempty"`
+	Attributes []HookAttribute `json:"attributes"`
+	Events     []HookEvent     `json:"events"`
+}
+
+type HookEvent struct {
+	Type       string          `json:"type"`
+	Attributes []HookAttribute `json:"attributes"`
+}
+
+type HookAttribute struct {
+	Key   string `json:"key"`
+	Value string `json:"value"`
+}
+
+// SudoTracer wraps the wasm engine and records smart account hook invocations
+type SudoTracer struct {
+	wasmtypes.WasmerEngine
+	gasRegister wasmkeeper.GasRegister
+	invocations []HookInvocation
+}
+
+func (t *SudoTracer) Sudo(
+	checksum wasmvm.Checksum,
+	env wasmvmtypes.Env,
+	sudoMsg []byte,
+	store wasmvm.KVStore,
+	goapi wasmvm.GoAPI,
+	querier wasmvm.Querier,
+	gasMeter wasmvm.GasMeter,
+	gasLimit uint64,
+	deserCost wasmvmtypes.UFraction,
+) (*wasmvmtypes.Response, uint64, error) {
+	res, gasUsed, err := t.WasmerEngine.Sudo(checksum, env, sudoMsg, store, goapi, querier, gasMeter, gasLimit, deserCost)
+
+	var msg map[string]json.RawMessage
+	if json.Unmarshal(sudoMsg, &msg) != nil {
+		return res, gasUsed, err
+	}
+
+	for _, hook := range smartAccountHooks {
+		payload, ok := msg[hook]
+		if !ok {
+			continue
+		}
+
+		var args struct {
+			Msgs     json.RawMessage `json:"msgs"`
+			CallInfo json.RawMessage `json:"call_info"`
+			IsAuthz  bool            `json:"is_authz"`
+		}
+		if json.Unmarshal(payload, &args) != nil {
+			continue
+		}
+
+		invocation := HookInvocation{
+			Hook:     hook,
+			Contract: env.Contract.Address,
+			Msgs:     args.Msgs,
+			CallInfo: args.CallInfo,
+			IsAuthz:  args.IsAuthz,
+			// vm gas, converted to sdk gas like the wasm keeper does
+			GasUsed: t.gasRegister.FromWasmVMGas(gasUsed),
+		}
+		if err != nil {
+			invocation.Error = err.Error()
+		} else if res != nil {
+			invocation.Response = newHookResponse(res)
+		}
+
+		t.invocations = append(t.invocations, invocation)
+	}
+
+	return res, gasUsed, err
+}
+
+func (t *SudoTracer) Reset() {
+	t.invocations = nil
+}
+
+// Invocations never returns nil, so it always encodes to a json array
+func (t *SudoTracer) Invocations() []HookInvocation {
+	return append(make([]HookInvocation, 0, len(t.invocations)), t.invocations...)
+}
+
+func newHookResponse(res *wasmvmtypes.Response) *HookResponse {
+	hookRes := &HookResponse{
+		Data:       res.Data,
+		Attributes: newHookAttributes(res.Attributes),
+		Events:     make([]HookEvent, 0, len(res.Events)),
+	}
+	for _, e := range res.Events {
+		hookRes.Events = append(hookRes.Events, HookEvent{
+			Type:       e.Type,
+			Attributes: newHookAttributes(e.Attributes),
+		})
+	}
+
+	return hookRes
+}
+
+func newHookAttributes(attrs []wasmvmtypes.EventAttribute) []HookAttribute {
+	hookAttrs := make([]HookAttribute, 0, len(attrs))
+	for _, a := range attrs {
+		hookAttrs = append(hookAttrs, HookAttribute{Key: a.Key, Value: a.Value})
+	}
+
+	return hookAttrs
+}
+
+var (
+	wasmKeeperType         = reflect.TypeOf(wasmkeeper.Keeper{})
+	permissionedKeeperType = reflect.TypeOf(wasmkeeper.PermissionedKeeper{})
+)
+
+// InstallSudoTracer makes the app's wasm keepers run contracts through the tracer.
+//
+// app.New takes no wasm keeper options, so the engine is swapped after construction on the
+// app's wasm keeper and on the copies the app's other keepers hold, either directly or
+// through a permissioned keeper.
+func InstallSudoTracer(appObj *app.App) *SudoTracer {
+	tracer := &SudoTracer{}
+	tracer.patchKeeper(reflect.ValueOf(&appObj.WasmKeeper).Elem())
+
+	keepers := reflect.ValueOf(appObj).Elem()
+	for i := 0; i < keepers.NumField(); i++ {
+		keeper := indirect(exported(keepers.Field(i)))
+		if !keeper.IsValid() || keeper.Kind() != reflect.Struct {
+			continue
+		}
+		if keeper.Type() == permissionedKeeperType {
+			tracer.patchPermissionedKeeper(keeper)
+			continue
+		}
+		for j := 0; j < keeper.NumField(); j++ {
+			tracer.patchField(exported(keeper.Field(j)))
+		}
+	}
+	requireNoNil("traced wasm engine", tracer.WasmerEngine)
+
+	return tracer
+}
+
+// patchField patches the wasm keeper a keeper field holds, by value, pointer or interface
+func (t *SudoTracer) patchField(field reflect.Value) {
+	if field.Kind() == reflect.Interface {
+		if field.IsNil() {
+			return
+		}
+		// a keeper stored by value in an interface can't be patched in place
+		if field.Elem().Type() == wasmKeeperType {
+			keeper := reflect.New(wasmKeeperType).Elem()
+			keeper.Set(field.Elem())
+			t.patchKeeper(keeper)
+			field.Set(keeper)
+			return
+		}
+		field = field.Elem()
+	}
+
+	// other values held in an interface aren't addressable
+	switch field = indirect(field); {
+	case !field.IsValid() || !field.CanAddr():
+	case field.Type() == wasmKeeperType:
+		t.patchKeeper(field)
+	case field.Type() == permissionedKeeperType:
+		t.patchPermissionedKeeper(field)
+	}
+}
+
+func (t *SudoTracer) patchPermissionedKeeper(keeper reflect.Value) {
+	t.patchField(exported(keeper.FieldByName("nested")))
+}
+
+func (t *SudoTracer) patchKeeper(keeper reflect.Value) {
+	engine := exported(keeper.FieldByName("wasmVM"))
+	if _, traced := engine.Interface().(*SudoTracer); traced || engine.IsNil() {
+		return
+	}
+
+	t.WasmerEngine = engine.Interface().(wasmtypes.WasmerEngine)
+	t.gasRegister = exported(keeper.FieldByName("gasRegister")).Interface().(wasmkeeper.GasRegister)
+	engine.Set(reflect.ValueOf(t))
+}
+
+// indirect follows a pointer, returning the zero Value for nil
+func indirect(v reflect.Value) reflect.Value {
+	if v.Kind() != reflect.Ptr {
+		return v
+	}
+	if v.IsNil() {
+		return reflect.Value{}
+	}
+
+	return v.Elem()
+}
+
+// exported gives a settable view of a possibly unexported, addressable field
+func exported(field reflect.Value) reflect.Value {
+	return reflect.NewAt(field.Type(), unsafe.Pointer(field.UnsafeAddr())).Elem()
+}
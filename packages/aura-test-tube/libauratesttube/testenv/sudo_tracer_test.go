@@ -0,0 +1,67 @@
+package testenv
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestInstallSudoTracerPatchesEveryWasmKeeper(t *testing.T) {
+	appObj := SetupAuraApp()
+	tracer := InstallSudoTracer(appObj)
+
+	keepers, untraced := wasmKeeperCopies(reflect.ValueOf(appObj), "app", tracer, map[visitKey]bool{})
+	if keepers == 0 {
+		t.Fatal("no wasm keeper found in the app")
+	}
+	for _, path := range untraced {
+		t.Errorf("wasm keeper at %s does not run contracts through the tracer", path)
+	}
+}
+
+type visitKey struct {
+	ptr uintptr
+	typ reflect.Type
+}
+
+// wasmKeeperCopies walks the whole app, counting the wasm keeper copies
+// and returning the paths of those whose engine is not the tracer.
+func wasmKeeperCopies(v reflect.Value, path string, tracer *SudoTracer, visited map[visitKey]bool) (int, []string) {
+	switch v.Kind() {
+	case reflect.Ptr:
+		if v.IsNil() {
+			return 0, nil
+		}
+		key := visitKey{v.Pointer(), v.Type()}
+		if visited[key] {
+			return 0, nil
+		}
+		visited[key] = true
+		return wasmKeeperCopies(v.Elem(), path, tracer, visited)
+
+	case reflect.Interface:
+		if v.IsNil() {
+			return 0, nil
+		}
+		return wasmKeeperCopies(v.Elem(), path, tracer, visited)
+
+	case reflect.Struct:
+		keepers := 0
+		var untraced []string
+		if v.Type() == wasmKeeperType {
+			keepers++
+			// the field type can be read without exporting it
+			engine := v.FieldByName("wasmVM")
+			if engine.IsNil() || engine.Elem().Type() != reflect.TypeOf(tracer) {
+				untraced = append(untraced, path)
+			}
+		}
+		for i := 0; i < v.NumField(); i++ {
+			n, paths := wasmKeeperCopies(v.Field(i), path+"."+v.Type().Field(i).Name, tracer, visited)
+			keepers += n
+			untraced = append(untraced, paths...)
+		}
+		return keepers, untraced
+	}
+
+	return 0, nil
+}
@@ -0,0 +1,124 @@
+use std::ops::Deref;
+
+use cosmrs::crypto::PublicKey;
+use cosmrs::AccountId;
+use test_tube::account::{Account, FeeSetting, SigningAccount};
+use test_tube::runner::error::RunnerError;
+use test_tube::runner::result::RunnerResult;
+
+/// Signer of a smart account.
+///
+/// The address is the smart account's, not the one derived from the signing key,
+/// so `account_id` returns the smart account. Derefs to `SigningAccount`, so it can
+/// be passed wherever a runner or module takes a signer.
+///
+/// Beware that `account_id` of the deref target is derived from the key: once coerced,
+/// e.g. `(*signer).account_id()` or `signing_account().account_id()`, it is the key's
+/// address, not the smart account. Call `account_id` on the signer itself.
+pub struct SmartAccountSigner {
+    inner: SigningAccount,
+}
+
+impl SmartAccountSigner {
+    /// Fails if `address` is not a valid bech32 address.
+    pub fn new(inner: SigningAccount) -> RunnerResult<Self> {
+        inner
+            .address()
+            .parse::<AccountId>()
+            .map_err(|e| RunnerError::ExecuteError {
+                msg: format!("invalid smart account address `{}`: {}", inner.address(), e),
+            })?;
+
+        Ok(Self { inner })
+    }
+
+    /// Fees are estimated by simulation with `FeeSetting::Auto` and `FeeSetting::AutoCapped`,
+    /// which needs the smart account to be activated.
+    pub fn with_fee_setting(self, fee_setting: FeeSetting) -> Self {
+        Self {
+            inner: self.inner.with_fee_setting(fee_setting),
+        }
+    }
+
+    pub fn with_fee_granter(self, granter: &str) -> Self {
+        Self {
+            inner: self.inner.with_fee_granter(granter),
+        }
+    }
+
+    pub fn signing_account(&self) -> &SigningAccount {
+        &self.inner
+    }
+
+    pub fn into_signing_account(self) -> SigningAccount {
+        self.inner
+    }
+}
+
+impl Account for SmartAccountSigner {
+    fn public_key(&self) -> PublicKey {
+        self.inner.public_key()
+    }
+    fn private_key(&self) -> Vec<u8> {
+        self.inner.private_key()
+    }
+    fn address(&self) -> String {
+        self.inner.address()
+    }
+    /// Parsed from the smart account address, the inner `SigningAccount` derives it from the key.
+    fn account_id(&self) -> AccountId {
+        self.inner
+            .address()
+            .parse()
+            .expect("address is checked to be valid bech32 in new")
+    }
+}
+
+impl Deref for SmartAccountSigner {
+    type Target = SigningAccount;
+
+    fn deref(&self) -> &SigningAccount {
+        &self.inner
+    }
+}
+
+impl AsRef<SigningAccount> for SmartAccountSigner {
+    fn as_ref(&self) -> &SigningAccount {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::crypto::secp256k1::SigningKey;
+    use test_tube::account::{Account, FeeSetting, SigningAccount};
+
+    use crate::SmartAccountSigner;
+
+    #[test]
+    fn test_account_id_through_deref_is_the_key() {
+        let private_key = vec![1u8; 32];
+        let signing_key = SigningKey::from_bytes(&private_key).unwrap();
+        let smart_account = "aura17xpfvakm2amg962yls6f84z3kell8c5lt05zfy";
+        let signer = SmartAccountSigner::new(SigningAccount::new(
+            smart_account.to_string(),
+            signing_key,
+            private_key,
+            FeeSetting::Auto {
+                gas_price: 0.025,
+                gas_adjustment: 2.0,
+            },
+        ))
+        .unwrap();
+
+        assert_eq!(signer.account_id().to_string(), smart_account);
+        assert_eq!(signer.address(), smart_account);
+
+        // the deref target keeps the address but derives the account id from the key
+        let key_account = signer.public_key().account_id("aura").unwrap();
+        assert_eq!((*signer).address(), smart_account);
+        assert_eq!((*signer).account_id(), key_account);
+        assert_eq!(signer.signing_account().account_id(), key_account);
+        assert_ne!(key_account.to_string(), smart_account);
+    }
+}
@@ -0,0 +1,229 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmos_sdk_proto::cosmwasm::wasm::v1::AccessType;
+use sha2::{Digest, Sha256};
+
+use test_tube::account::SigningAccount;
+use test_tube::module::Module;
+use test_tube::runner::error::RunnerError;
+use test_tube::runner::result::RunnerResult;
+use test_tube::runner::Runner;
+
+use crate::module::Wasm;
+
+/// Overrides the artifacts directory, which is otherwise the closest
+/// `artifacts/` directory with a `checksums.txt` above the working directory.
+pub const ARTIFACTS_DIR_ENV: &str = "AURA_TEST_TUBE_ARTIFACTS_DIR";
+
+const CHECKSUMS_FILE: &str = "checksums.txt";
+
+/// Loads contract artifacts by name, verifies them against `checksums.txt`
+/// and stores each of them once per app.
+///
+/// ```ignore
+/// let artifacts = ArtifactRegistry::new(&app)?;
+/// let code_id = artifacts.store_code("spend-limit", &acc)?;
+/// ```
+pub struct ArtifactRegistry<'a, R: Runner<'a>> {
+    wasm: Wasm<'a, R>,
+    dir: PathBuf,
+    code_ids: RefCell<HashMap<String, u64>>,
+}
+
+impl<'a, R> ArtifactRegistry<'a, R>
+where
+    R: Runner<'a>,
+{
+    /// Registry over `AURA_TEST_TUBE_ARTIFACTS_DIR`, or the closest `artifacts/checksums.txt`
+    /// found by walking up from the working directory.
+    pub fn new(runner: &'a R) -> RunnerResult<Self> {
+        let dir = match std::env::var_os(ARTIFACTS_DIR_ENV) {
+            Some(dir) => PathBuf::from(dir),
+            None => find_artifacts_dir()?,
+        };
+
+        Ok(Self::with_dir(runner, dir))
+    }
+
+    /// Registry over the given directory, which must contain `checksums.txt`.
+    pub fn with_dir(runner: &'a R, dir: impl Into<PathBuf>) -> Self {
+        ArtifactRegistry {
+            wasm: Wasm::new(runner),
+            dir: dir.into(),
+            code_ids: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the artifact for a contract, e.g. `spend-limit` -> `spend_limit.wasm`.
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(file_name(name))
+    }
+
+    /// Read the artifact for a contract, failing if it is not listed in `checksums.txt`
+    /// or its checksum does not match.
+    pub fn read(&self, name: &str) -> RunnerResult<Vec<u8>> {
+        let file_name = file_name(name);
+        let expected = self
+            .checksums()?
+            .remove(&file_name)
+            .ok_or_else(|| RunnerError::ArtifactError {
+                msg: format!("`{}` is not listed in {}", file_name, CHECKSUMS_FILE),
+            })?;
+
+        let path = self.path(name);
+        let code = fs::read(&path).map_err(|e| RunnerError::ArtifactError {
+            msg: format!("{}: {}", path.display(), e),
+        })?;
+
+        let actual = hex_sha256(&code);
+        if actual != expected {
+            return Err(RunnerError::ArtifactError {
+                msg: format!(
+                    "{} is stale: checksum {} does not match {} in {}",
+                    path.display(),
+                    actual,
+                    expected,
+                    CHECKSUMS_FILE
+                ),
+            });
+        }
+
+        Ok(code)
+    }
+
+    /// Store the artifact for a contract and return its code id.
+    /// Code already stored on this app with the same checksum is reused.
+    pub fn store_code(&self, name: &str, signer: &SigningAccount) -> RunnerResult<u64> {
+        let file_name = file_name(name);
+        if let Some(code_id) = self.code_ids.borrow().get(&file_name) {
+            return Ok(*code_id);
+        }
+
+        let code = self.read(name)?;
+        let code_id = match self.find_code_id(&Sha256::digest(&code))? {
+            Some(code_id) => code_id,
+            None => self.wasm.store_code(&code, None, signer)?.data.code_id,
+        };
+
+        self.code_ids.borrow_mut().insert(file_name, code_id);
+
+        Ok(code_id)
+    }
+
+    /// Code stored by anyone with the same checksum, instantiable by everybody.
+    fn find_code_id(&self, data_hash: &[u8]) -> RunnerResult<Option<u64>> {
+        let mut pagination = None;
+        loop {
+            let res = self.wasm.query_codes(pagination)?;
+            let found = res.code_infos.into_iter().find(|info| {
+                info.data_hash == data_hash
+                    && info
+                        .instantiate_permission
+                        .as_ref()
+                        .is_none_or(|p| p.permission == AccessType::Everybody as i32)
+            });
+            if let Some(info) = found {
+                return Ok(Some(info.code_id));
+            }
+
+            match res.pagination {
+                Some(page) if !page.next_key.is_empty() => {
+                    pagination = Some(PageRequest {
+                        key: page.next_key,
+                        ..Default::default()
+                    })
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    /// `checksums.txt` as written by the workspace optimizer, `<sha256 hex>  <file name>` per line.
+    fn checksums(&self) -> RunnerResult<HashMap<String, String>> {
+        let path = self.dir.join(CHECKSUMS_FILE);
+        let checksums = fs::read_to_string(&path).map_err(|e| RunnerError::ArtifactError {
+            msg: format!("{}: {}", path.display(), e),
+        })?;
+
+        Ok(checksums
+            .lines()
+            .filter_map(|line| line.split_once(char::is_whitespace))
+            .map(|(checksum, file_name)| (file_name.trim().to_string(), checksum.to_lowercase()))
+            .collect())
+    }
+}
+
+fn file_name(name: &str) -> String {
+    let name = name.strip_suffix(".wasm").unwrap_or(name);
+    format!("{}.wasm", name.replace('-', "_"))
+}
+
+fn hex_sha256(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn find_artifacts_dir() -> RunnerResult<PathBuf> {
+    let cwd = std::env::current_dir().map_err(|e| RunnerError::ArtifactError {
+        msg: e.to_string(),
+    })?;
+
+    cwd.ancestors()
+        .map(|dir| dir.join("artifacts"))
+        .find(|dir| dir.join(CHECKSUMS_FILE).is_file())
+        .ok_or_else(|| RunnerError::ArtifactError {
+            msg: format!(
+                "no artifacts/{} found above {}, set {}",
+                CHECKSUMS_FILE,
+                cwd.display(),
+                ARTIFACTS_DIR_ENV
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+
+    use super::ArtifactRegistry;
+    use crate::AuraTestApp;
+
+    #[test]
+    fn test_store_code_once_per_app() {
+        let app = AuraTestApp::default();
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        let artifacts = ArtifactRegistry::new(&app).unwrap();
+        let code_id = artifacts.store_code("listener", &acc).unwrap();
+        assert_eq!(artifacts.store_code("listener.wasm", &acc).unwrap(), code_id);
+        assert_ne!(artifacts.store_code("spend-limit", &acc).unwrap(), code_id);
+
+        // another registry on the same app finds the stored code by checksum
+        let artifacts = ArtifactRegistry::new(&app).unwrap();
+        assert_eq!(artifacts.store_code("listener", &acc).unwrap(), code_id);
+    }
+
+    #[test]
+    fn test_reject_stale_artifact() {
+        let app = AuraTestApp::default();
+        let dir = std::env::temp_dir().join(format!("aura-artifacts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("checksums.txt"), format!("{}  listener.wasm\n", "00".repeat(32)))
+            .unwrap();
+        std::fs::copy(ArtifactRegistry::new(&app).unwrap().path("listener"), dir.join("listener.wasm"))
+            .unwrap();
+
+        let artifacts = ArtifactRegistry::with_dir(&app, &dir);
+        assert!(artifacts.read("listener").unwrap_err().to_string().contains("stale"));
+        assert!(artifacts.read("spend-limit").unwrap_err().to_string().contains("not listed"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,17 +1,24 @@
+mod account;
+mod artifacts;
 mod module;
+pub mod proto;
 mod runner;
 
 pub use cosmrs;
 
 pub use aura_proto::*;
 
+pub use account::SmartAccountSigner;
+pub use artifacts::{ArtifactRegistry, ARTIFACTS_DIR_ENV};
 pub use module::*;
-pub use runner::app::AuraTestApp;
+pub use runner::app::{AuraTestApp, DEFAULT_HD_PATH};
 pub use runner::helpers::init_local_smart_account;
-pub use test_tube::account::{Account, NonSigningAccount, SigningAccount};
+pub use test_tube::account::{Account, FeeSetting, NonSigningAccount, SigningAccount};
 pub use test_tube::runner::error::{DecodeError, EncodeError, RunnerError};
+pub use test_tube::runner::gas::{GasDiff, GasRecorder};
+pub use test_tube::runner::hooks::{CallInfo, HookInvocation, HookMsg, HookResponse};
 pub use test_tube::runner::result::{ExecuteResponse, RunnerExecuteResult, RunnerResult};
 pub use test_tube::BaseApp;
 pub use test_tube::runner::Runner;
 pub use test_tube::module::*;
-pub use test_tube::{fn_execute, fn_query};
+pub use test_tube::{assert_event, assert_no_event, fn_execute, fn_query};
@@ -0,0 +1,162 @@
+use aura_proto::types::smartaccount::v1beta1::SmartAccount as SmartAccountProto;
+use cosmos_sdk_proto::cosmos::auth::v1beta1::{
+    BaseAccount, Bech32PrefixRequest, Bech32PrefixResponse, ModuleAccount, QueryAccountRequest,
+    QueryAccountResponse, QueryAccountsRequest, QueryAccountsResponse,
+    QueryModuleAccountByNameRequest, QueryModuleAccountByNameResponse, QueryModuleAccountsRequest,
+    QueryModuleAccountsResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use cosmos_sdk_proto::traits::Message;
+use cosmos_sdk_proto::Any;
+use test_tube::fn_query;
+
+use test_tube::module::Module;
+use test_tube::runner::error::{DecodeError, RunnerError};
+use test_tube::runner::result::RunnerResult;
+use test_tube::runner::Runner;
+
+/// An account returned by the auth module, decoded by its type url.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuthAccount {
+    Base(BaseAccount),
+    Module(ModuleAccount),
+    Smart(SmartAccountProto),
+    /// Any account type not known by this crate, left encoded.
+    Other(Any),
+}
+
+impl AuthAccount {
+    pub fn decode(account: Any) -> RunnerResult<Self> {
+        let value = account.value.as_slice();
+        let account = match account.type_url.as_str() {
+            "/cosmos.auth.v1beta1.BaseAccount" => {
+                Self::Base(BaseAccount::decode(value).map_err(DecodeError::ProtoDecodeError)?)
+            }
+            "/cosmos.auth.v1beta1.ModuleAccount" => {
+                Self::Module(ModuleAccount::decode(value).map_err(DecodeError::ProtoDecodeError)?)
+            }
+            "/aura.smartaccount.v1beta1.SmartAccount" => Self::Smart(
+                SmartAccountProto::decode(value).map_err(DecodeError::ProtoDecodeError)?,
+            ),
+            _ => Self::Other(account),
+        };
+
+        Ok(account)
+    }
+
+    /// Bech32 address, `None` for unknown account types.
+    pub fn address(&self) -> Option<&str> {
+        match self {
+            Self::Base(acc) => Some(&acc.address),
+            Self::Module(acc) => acc.base_account.as_ref().map(|b| b.address.as_str()),
+            Self::Smart(acc) => Some(&acc.address),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+pub struct Auth<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Auth<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Auth<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_query! {
+        pub query_account ["/cosmos.auth.v1beta1.Query/Account"]: QueryAccountRequest => QueryAccountResponse
+    }
+
+    fn_query! {
+        pub query_accounts ["/cosmos.auth.v1beta1.Query/Accounts"]: QueryAccountsRequest => QueryAccountsResponse
+    }
+
+    fn_query! {
+        pub query_module_accounts ["/cosmos.auth.v1beta1.Query/ModuleAccounts"]: QueryModuleAccountsRequest => QueryModuleAccountsResponse
+    }
+
+    fn_query! {
+        pub query_module_account_by_name ["/cosmos.auth.v1beta1.Query/ModuleAccountByName"]: QueryModuleAccountByNameRequest => QueryModuleAccountByNameResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.auth.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_bech32_prefix ["/cosmos.auth.v1beta1.Query/Bech32Prefix"]: Bech32PrefixRequest => Bech32PrefixResponse
+    }
+
+    /// Decoded account stored at `address`. Fails if the address has no account.
+    pub fn account(&self, address: &str) -> RunnerResult<AuthAccount> {
+        let account = self
+            .query_account(&QueryAccountRequest {
+                address: address.to_owned(),
+            })?
+            .account
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("account {} not found", address),
+            })?;
+
+        AuthAccount::decode(account)
+    }
+
+    /// Module account registered under `name`, e.g. "fee_collector".
+    pub fn module_account(&self, name: &str) -> RunnerResult<ModuleAccount> {
+        let account = self
+            .query_module_account_by_name(&QueryModuleAccountByNameRequest {
+                name: name.to_owned(),
+            })?
+            .account
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("module account {} not found", name),
+            })?;
+
+        match AuthAccount::decode(account)? {
+            AuthAccount::Module(acc) => Ok(acc),
+            other => Err(RunnerError::QueryError {
+                msg: format!("{} is not a module account: {:?}", name, other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::auth::v1beta1::Bech32PrefixRequest;
+    use cosmwasm_std::coins;
+
+    use crate::{Account, Auth, AuraTestApp, AuthAccount, Module};
+
+    #[test]
+    fn test_accounts() {
+        let app = AuraTestApp::default();
+        let auth = Auth::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        let prefix = auth
+            .query_bech32_prefix(&Bech32PrefixRequest {})
+            .unwrap()
+            .bech32_prefix;
+        assert_eq!(prefix, "aura");
+
+        match auth.account(&acc.address()).unwrap() {
+            AuthAccount::Base(base) => {
+                assert_eq!(base.address, acc.address());
+                assert!(base.pub_key.is_none());
+            }
+            other => panic!("unexpected account {:?}", other),
+        }
+
+        let fee_collector = auth.module_account("fee_collector").unwrap();
+        assert_eq!(
+            fee_collector.base_account.unwrap().address,
+            "aura17xpfvakm2amg962yls6f84z3kell8c5lt05zfy"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use cosmos_sdk_proto::cosmos::authz::v1beta1::{
+    GenericAuthorization, Grant, MsgExec, MsgExecResponse, MsgGrant, MsgGrantResponse, MsgRevoke,
+    MsgRevokeResponse, QueryGranteeGrantsRequest, QueryGranteeGrantsResponse,
+    QueryGranterGrantsRequest, QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::SendAuthorization;
+use cosmos_sdk_proto::traits::MessageExt;
+use cosmos_sdk_proto::Any;
+use cosmwasm_std::Coin;
+use test_tube::{fn_execute, fn_query};
+
+use test_tube::account::{Account, SigningAccount};
+use test_tube::module::Module;
+use test_tube::runner::result::RunnerExecuteResult;
+use test_tube::runner::Runner;
+
+use crate::module::{coin_to_proto, to_any};
+use crate::proto::cosmwasm::wasm::v1::{
+    AllowAllMessagesFilter, ContractExecutionAuthorization, ContractGrant, MaxCallsLimit,
+};
+
+pub struct Authz<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Authz<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Authz<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub grant: MsgGrant["/cosmos.authz.v1beta1.MsgGrant"] => MsgGrantResponse
+    }
+
+    fn_execute! {
+        pub exec: MsgExec["/cosmos.authz.v1beta1.MsgExec"] => MsgExecResponse
+    }
+
+    fn_execute! {
+        pub revoke: MsgRevoke["/cosmos.authz.v1beta1.MsgRevoke"] => MsgRevokeResponse
+    }
+
+    fn_query! {
+        pub query_grants ["/cosmos.authz.v1beta1.Query/Grants"]: QueryGrantsRequest => QueryGrantsResponse
+    }
+
+    fn_query! {
+        pub query_granter_grants ["/cosmos.authz.v1beta1.Query/GranterGrants"]: QueryGranterGrantsRequest => QueryGranterGrantsResponse
+    }
+
+    fn_query! {
+        pub query_grantee_grants ["/cosmos.authz.v1beta1.Query/GranteeGrants"]: QueryGranteeGrantsRequest => QueryGranteeGrantsResponse
+    }
+
+    /// Allow `grantee` to execute any message of type `msg_type_url` on behalf of the signer,
+    /// without expiration.
+    pub fn grant_generic(
+        &self,
+        grantee: &str,
+        msg_type_url: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantResponse> {
+        let authorization = GenericAuthorization {
+            msg: msg_type_url.to_owned(),
+        };
+
+        self.grant_authorization(
+            grantee,
+            "/cosmos.authz.v1beta1.GenericAuthorization",
+            authorization,
+            signer,
+        )
+    }
+
+    /// Allow `grantee` to send up to `spend_limit` from the signer, without expiration.
+    pub fn grant_send(
+        &self,
+        grantee: &str,
+        spend_limit: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantResponse> {
+        let authorization = SendAuthorization {
+            spend_limit: spend_limit.iter().map(coin_to_proto).collect(),
+        };
+
+        self.grant_authorization(
+            grantee,
+            "/cosmos.bank.v1beta1.SendAuthorization",
+            authorization,
+            signer,
+        )
+    }
+
+    /// Allow `grantee` to execute `contract` with any message up to `max_calls` times
+    /// on behalf of the signer, without expiration.
+    pub fn grant_contract_execution(
+        &self,
+        grantee: &str,
+        contract: &str,
+        max_calls: u64,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantResponse> {
+        let authorization = ContractExecutionAuthorization {
+            grants: vec![ContractGrant {
+                contract: contract.to_owned(),
+                limit: Some(to_any(
+                    "/cosmwasm.wasm.v1.MaxCallsLimit",
+                    MaxCallsLimit {
+                        remaining: max_calls,
+                    },
+                )?),
+                filter: Some(to_any(
+                    "/cosmwasm.wasm.v1.AllowAllMessagesFilter",
+                    AllowAllMessagesFilter {},
+                )?),
+            }],
+        };
+
+        self.grant_authorization(
+            grantee,
+            "/cosmwasm.wasm.v1.ContractExecutionAuthorization",
+            authorization,
+            signer,
+        )
+    }
+
+    /// Execute `msgs` on behalf of their granters. Only the grantee signs the `MsgExec`.
+    pub fn exec_msgs(
+        &self,
+        msgs: Vec<Any>,
+        grantee: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecResponse> {
+        self.exec(
+            MsgExec {
+                grantee: grantee.address(),
+                msgs,
+            },
+            grantee,
+        )
+    }
+
+    fn grant_authorization<A>(
+        &self,
+        grantee: &str,
+        type_url: &str,
+        authorization: A,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantResponse>
+    where
+        A: MessageExt,
+    {
+        self.grant(
+            MsgGrant {
+                granter: signer.address(),
+                grantee: grantee.to_owned(),
+                grant: Some(Grant {
+                    authorization: Some(to_any(type_url, authorization)?),
+                    expiration: None,
+                }),
+            },
+            signer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::QueryGrantsRequest;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgRevoke;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::traits::MessageExt;
+    use cosmos_sdk_proto::Any;
+    use cosmwasm_std::{coins, Uint128};
+
+    use crate::{Account, AuraTestApp, Authz, Bank, Module};
+
+    #[test]
+    fn test_grant_exec_revoke() {
+        let app = AuraTestApp::default();
+        let authz = Authz::new(&app);
+        let bank = Bank::new(&app);
+        let accs = app
+            .init_base_accounts(&coins(100_000_000_000, "uaura"), 3)
+            .unwrap();
+        let (granter, grantee, to) = (&accs[0], &accs[1], &accs[2]);
+
+        authz
+            .grant_send(&grantee.address(), &coins(1_000, "uaura"), granter)
+            .unwrap();
+        let grants = authz
+            .query_grants(&QueryGrantsRequest {
+                granter: granter.address(),
+                grantee: grantee.address(),
+                msg_type_url: String::new(),
+                pagination: None,
+            })
+            .unwrap()
+            .grants;
+        assert_eq!(grants.len(), 1);
+
+        let send = |amount: &str| Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: MsgSend {
+                from_address: granter.address(),
+                to_address: to.address(),
+                amount: vec![Coin {
+                    denom: "uaura".to_string(),
+                    amount: amount.to_string(),
+                }],
+            }
+            .to_bytes()
+            .unwrap(),
+        };
+
+        let before = bank.balance(&to.address(), "uaura").unwrap();
+        authz.exec_msgs(vec![send("600")], grantee).unwrap();
+        assert_eq!(bank.balance(&to.address(), "uaura").unwrap(), before + Uint128::new(600));
+
+        // spend limit is used up
+        assert!(authz.exec_msgs(vec![send("600")], grantee).is_err());
+
+        authz
+            .revoke(
+                MsgRevoke {
+                    granter: granter.address(),
+                    grantee: grantee.address(),
+                    msg_type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                },
+                granter,
+            )
+            .unwrap();
+        assert!(authz.exec_msgs(vec![send("100")], grantee).is_err());
+    }
+}
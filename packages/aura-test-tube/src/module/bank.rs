@@ -1,12 +1,24 @@
 use cosmos_sdk_proto::cosmos::bank::v1beta1::{
-    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse,
-    QueryTotalSupplyRequest, QueryTotalSupplyResponse,
+    MsgMultiSend, MsgMultiSendResponse, MsgSend, MsgSendResponse, QueryAllBalancesRequest,
+    QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse,
+    QueryDenomMetadataRequest, QueryDenomMetadataResponse, QueryDenomsMetadataRequest,
+    QueryDenomsMetadataResponse, QueryParamsRequest, QueryParamsResponse,
+    QuerySpendableBalancesRequest, QuerySpendableBalancesResponse, QuerySupplyOfRequest,
+    QuerySupplyOfResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
-use test_tube::fn_query;
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmwasm_std::{Coin, Uint128};
+use test_tube::{fn_execute, fn_query};
 
+use test_tube::account::{Account, SigningAccount};
 use test_tube::module::Module;
+use test_tube::runner::error::RunnerError;
+use test_tube::runner::result::{RunnerExecuteResult, RunnerResult};
 use test_tube::runner::Runner;
 
+use crate::module::coin_to_proto;
+use crate::proto::cosmos::bank::v1beta1::{QuerySendEnabledRequest, QuerySendEnabledResponse};
+
 pub struct Bank<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -21,6 +33,14 @@ impl<'a, R> Bank<'a, R>
 where
     R: Runner<'a>,
 {
+    fn_execute! {
+        pub send: MsgSend["/cosmos.bank.v1beta1.MsgSend"] => MsgSendResponse
+    }
+
+    fn_execute! {
+        pub multi_send: MsgMultiSend["/cosmos.bank.v1beta1.MsgMultiSend"] => MsgMultiSendResponse
+    }
+
     fn_query! {
         pub query_balance ["/cosmos.bank.v1beta1.Query/Balance"]: QueryBalanceRequest => QueryBalanceResponse
     }
@@ -29,7 +49,187 @@ where
         pub query_all_balances ["/cosmos.bank.v1beta1.Query/AllBalances"]: QueryAllBalancesRequest => QueryAllBalancesResponse
     }
 
+    fn_query! {
+        pub query_spendable_balances ["/cosmos.bank.v1beta1.Query/SpendableBalances"]: QuerySpendableBalancesRequest => QuerySpendableBalancesResponse
+    }
+
     fn_query! {
         pub query_total_supply ["/cosmos.bank.v1beta1.Query/TotalSupply"]: QueryTotalSupplyRequest => QueryTotalSupplyResponse
     }
+
+    fn_query! {
+        pub query_supply_of ["/cosmos.bank.v1beta1.Query/SupplyOf"]: QuerySupplyOfRequest => QuerySupplyOfResponse
+    }
+
+    fn_query! {
+        pub query_denom_metadata ["/cosmos.bank.v1beta1.Query/DenomMetadata"]: QueryDenomMetadataRequest => QueryDenomMetadataResponse
+    }
+
+    fn_query! {
+        pub query_denoms_metadata ["/cosmos.bank.v1beta1.Query/DenomsMetadata"]: QueryDenomsMetadataRequest => QueryDenomsMetadataResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.bank.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_send_enabled ["/cosmos.bank.v1beta1.Query/SendEnabled"]: QuerySendEnabledRequest => QuerySendEnabledResponse
+    }
+
+    /// Send coins from the signer to `to_address`.
+    pub fn send_coins(
+        &self,
+        to_address: &str,
+        amount: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgSendResponse> {
+        self.send(
+            MsgSend {
+                from_address: signer.address(),
+                to_address: to_address.to_owned(),
+                amount: amount.iter().map(coin_to_proto).collect(),
+            },
+            signer,
+        )
+    }
+
+    /// Balance of a single denom, zero if the address holds none.
+    pub fn balance(&self, address: &str, denom: &str) -> RunnerResult<Uint128> {
+        let res = self.query_balance(&QueryBalanceRequest {
+            address: address.to_owned(),
+            denom: denom.to_owned(),
+        })?;
+
+        match res.balance {
+            Some(coin) => parse_amount(&coin.amount),
+            None => Ok(Uint128::zero()),
+        }
+    }
+
+    pub fn all_balances(&self, address: &str) -> RunnerResult<Vec<Coin>> {
+        let res = self.query_all_balances(&QueryAllBalancesRequest {
+            address: address.to_owned(),
+            pagination: None,
+        })?;
+
+        res.balances.into_iter().map(coin_from_proto).collect()
+    }
+
+    pub fn supply_of(&self, denom: &str) -> RunnerResult<Uint128> {
+        let res = self.query_supply_of(&QuerySupplyOfRequest {
+            denom: denom.to_owned(),
+        })?;
+
+        match res.amount {
+            Some(coin) => parse_amount(&coin.amount),
+            None => Ok(Uint128::zero()),
+        }
+    }
+}
+
+fn coin_from_proto(coin: ProtoCoin) -> RunnerResult<Coin> {
+    Ok(Coin {
+        amount: parse_amount(&coin.amount)?,
+        denom: coin.denom,
+    })
+}
+
+fn parse_amount(amount: &str) -> RunnerResult<Uint128> {
+    amount.parse().map_err(|_| RunnerError::QueryError {
+        msg: format!("invalid coin amount `{}`", amount),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+        Input, MsgMultiSend, Output, QueryParamsRequest, QuerySpendableBalancesRequest,
+    };
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmwasm_std::{coin, coins, Uint128};
+
+    use crate::proto::cosmos::bank::v1beta1::QuerySendEnabledRequest;
+    use crate::{Account, AuraTestApp, Bank, Module};
+
+    #[test]
+    fn test_send_and_balances() {
+        let app = AuraTestApp::default();
+        let bank = Bank::new(&app);
+        let accs = app
+            .init_base_accounts(&[coin(100_000_000_000, "uaura"), coin(100, "uatom")], 3)
+            .unwrap();
+        let (from, to, other) = (&accs[0], &accs[1], &accs[2]);
+
+        bank.send_coins(&to.address(), &coins(50, "uatom"), from).unwrap();
+        assert_eq!(bank.balance(&to.address(), "uatom").unwrap(), Uint128::new(150));
+        assert_eq!(bank.balance(&to.address(), "unknown").unwrap(), Uint128::zero());
+        assert_eq!(
+            bank.all_balances(&from.address()).unwrap()[0],
+            coin(50, "uatom")
+        );
+
+        let amount = |n: u128| {
+            vec![ProtoCoin {
+                denom: "uatom".to_string(),
+                amount: n.to_string(),
+            }]
+        };
+        bank.multi_send(
+            MsgMultiSend {
+                inputs: vec![Input {
+                    address: from.address(),
+                    coins: amount(20),
+                }],
+                outputs: vec![
+                    Output {
+                        address: to.address(),
+                        coins: amount(10),
+                    },
+                    Output {
+                        address: other.address(),
+                        coins: amount(10),
+                    },
+                ],
+            },
+            from,
+        )
+        .unwrap();
+        assert_eq!(bank.balance(&other.address(), "uatom").unwrap(), Uint128::new(110));
+        assert_eq!(bank.supply_of("uatom").unwrap(), Uint128::new(300));
+
+        let spendable = bank
+            .query_spendable_balances(&QuerySpendableBalancesRequest {
+                address: from.address(),
+                pagination: None,
+            })
+            .unwrap();
+        assert_eq!(spendable.balances.len(), 2);
+
+        let params = bank.query_params(&QueryParamsRequest {}).unwrap().params.unwrap();
+        assert!(params.default_send_enabled);
+        let send_enabled = bank
+            .query_send_enabled(&QuerySendEnabledRequest {
+                denoms: vec!["uatom".to_string()],
+                pagination: None,
+            })
+            .unwrap();
+        assert!(send_enabled.send_enabled.is_empty());
+    }
+
+    #[test]
+    fn test_all_balances() {
+        let app = AuraTestApp::default();
+        let bank = Bank::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        assert_eq!(
+            bank.all_balances(&acc.address()).unwrap(),
+            coins(100_000_000_000, "uaura")
+        );
+        assert_eq!(
+            bank.balance(&acc.address(), "uaura").unwrap(),
+            Uint128::new(100_000_000_000)
+        );
+    }
 }
@@ -0,0 +1,100 @@
+use std::marker::PhantomData;
+
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    MsgExecuteContractResponse, MsgMigrateContractResponse,
+};
+use cosmwasm_std::Coin;
+use serde::{de::DeserializeOwned, Serialize};
+
+use test_tube::account::SigningAccount;
+use test_tube::module::Module;
+use test_tube::runner::result::{RunnerExecuteResult, RunnerResult};
+use test_tube::runner::Runner;
+
+use super::{Bank, Wasm};
+
+/// Handle to an instantiated contract, typed by the contract's
+/// execute message `E` and query message `Q`.
+///
+/// ```ignore
+/// let listener: Contract<_, ExecuteMsg, QueryMsg> =
+///     Contract::instantiate(&wasm, code_id, &InstantiateMsg {}, None, None, &[], &acc)?;
+/// listener.execute(&ExecuteMsg::Listen {}, &[], &acc)?;
+/// ```
+pub struct Contract<'a, R: Runner<'a>, E, Q> {
+    wasm: &'a Wasm<'a, R>,
+    address: String,
+    msg_types: PhantomData<(E, Q)>,
+}
+
+impl<'a, R, E, Q> Contract<'a, R, E, Q>
+where
+    R: Runner<'a>,
+    E: Serialize,
+    Q: Serialize,
+{
+    /// Bind to an already instantiated contract.
+    pub fn new(wasm: &'a Wasm<'a, R>, address: &str) -> Self {
+        Contract {
+            wasm,
+            address: address.to_owned(),
+            msg_types: PhantomData,
+        }
+    }
+
+    /// Instantiate a new contract and bind to it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn instantiate<I>(
+        wasm: &'a Wasm<'a, R>,
+        code_id: u64,
+        msg: &I,
+        admin: Option<&str>,
+        label: Option<&str>,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerResult<Self>
+    where
+        I: ?Sized + Serialize,
+    {
+        let res = wasm.instantiate(code_id, msg, admin, label, funds, signer)?;
+
+        Ok(Self::new(wasm, &res.data.address))
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn execute(
+        &self,
+        msg: &E,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.wasm.execute(&self.address, msg, funds, signer)
+    }
+
+    pub fn query<Res>(&self, msg: &Q) -> RunnerResult<Res>
+    where
+        Res: DeserializeOwned,
+    {
+        self.wasm.query(&self.address, msg)
+    }
+
+    pub fn migrate<M>(
+        &self,
+        new_code_id: u64,
+        msg: &M,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgMigrateContractResponse>
+    where
+        M: ?Sized + Serialize,
+    {
+        self.wasm.migrate(&self.address, new_code_id, msg, signer)
+    }
+
+    /// All coins held by the contract.
+    pub fn balance(&self) -> RunnerResult<Vec<Coin>> {
+        Bank::new(self.wasm.runner).all_balances(&self.address)
+    }
+}
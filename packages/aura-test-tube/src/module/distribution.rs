@@ -0,0 +1,224 @@
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
+    MsgFundCommunityPool, MsgFundCommunityPoolResponse, MsgSetWithdrawAddress,
+    MsgSetWithdrawAddressResponse, MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse,
+    MsgWithdrawValidatorCommission, MsgWithdrawValidatorCommissionResponse,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+};
+use test_tube::{fn_execute, fn_query};
+
+use test_tube::module::Module;
+use test_tube::runner::Runner;
+
+pub struct Distribution<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Distribution<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Distribution<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub withdraw_delegator_reward: MsgWithdrawDelegatorReward["/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"] => MsgWithdrawDelegatorRewardResponse
+    }
+
+    fn_execute! {
+        pub set_withdraw_address: MsgSetWithdrawAddress["/cosmos.distribution.v1beta1.MsgSetWithdrawAddress"] => MsgSetWithdrawAddressResponse
+    }
+
+    fn_execute! {
+        pub withdraw_validator_commission: MsgWithdrawValidatorCommission["/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission"] => MsgWithdrawValidatorCommissionResponse
+    }
+
+    fn_execute! {
+        pub fund_community_pool: MsgFundCommunityPool["/cosmos.distribution.v1beta1.MsgFundCommunityPool"] => MsgFundCommunityPoolResponse
+    }
+
+    fn_query! {
+        pub query_delegation_rewards ["/cosmos.distribution.v1beta1.Query/DelegationRewards"]: QueryDelegationRewardsRequest => QueryDelegationRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegation_total_rewards ["/cosmos.distribution.v1beta1.Query/DelegationTotalRewards"]: QueryDelegationTotalRewardsRequest => QueryDelegationTotalRewardsResponse
+    }
+
+    fn_query! {
+        pub query_community_pool ["/cosmos.distribution.v1beta1.Query/CommunityPool"]: QueryCommunityPoolRequest => QueryCommunityPoolResponse
+    }
+
+    fn_query! {
+        pub query_validator_outstanding_rewards ["/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards"]: QueryValidatorOutstandingRewardsRequest => QueryValidatorOutstandingRewardsResponse
+    }
+
+    fn_query! {
+        pub query_validator_commission ["/cosmos.distribution.v1beta1.Query/ValidatorCommission"]: QueryValidatorCommissionRequest => QueryValidatorCommissionResponse
+    }
+
+    fn_query! {
+        pub query_delegator_withdraw_address ["/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress"]: QueryDelegatorWithdrawAddressRequest => QueryDelegatorWithdrawAddressResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.distribution.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::base::v1beta1::{Coin, DecCoin};
+    use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
+        MsgFundCommunityPool, MsgSetWithdrawAddress, MsgWithdrawDelegatorReward,
+        QueryCommunityPoolRequest, QueryDelegationRewardsRequest,
+        QueryDelegatorWithdrawAddressRequest,
+    };
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgDelegate, QueryValidatorsRequest};
+    use cosmwasm_std::coins;
+
+    use crate::{Account, AuraTestApp, Bank, Distribution, Module, Staking};
+
+    #[test]
+    fn test_community_pool_and_withdraw_address() {
+        let app = AuraTestApp::default();
+        let distribution = Distribution::new(&app);
+        let accs = app
+            .init_base_accounts(&coins(100_000_000_000, "uaura"), 2)
+            .unwrap();
+        let (acc, withdrawer) = (&accs[0], &accs[1]);
+
+        distribution
+            .set_withdraw_address(
+                MsgSetWithdrawAddress {
+                    delegator_address: acc.address(),
+                    withdraw_address: withdrawer.address(),
+                },
+                acc,
+            )
+            .unwrap();
+        let res = distribution
+            .query_delegator_withdraw_address(&QueryDelegatorWithdrawAddressRequest {
+                delegator_address: acc.address(),
+            })
+            .unwrap();
+        assert_eq!(res.withdraw_address, withdrawer.address());
+
+        let pool_before = community_pool_uaura(&distribution);
+        distribution
+            .fund_community_pool(
+                MsgFundCommunityPool {
+                    amount: vec![Coin {
+                        denom: "uaura".to_string(),
+                        amount: "1000".to_string(),
+                    }],
+                    depositor: acc.address(),
+                },
+                acc,
+            )
+            .unwrap();
+        // the pool also collects a share of fees each block
+        assert!(community_pool_uaura(&distribution) >= pool_before + 1000);
+    }
+
+    #[test]
+    fn test_delegation_rewards() {
+        let app = AuraTestApp::default();
+        let distribution = Distribution::new(&app);
+        let staking = Staking::new(&app);
+        let bank = Bank::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let withdrawer = app.init_base_account(&coins(1, "uaura")).unwrap();
+
+        let validator = staking
+            .query_validators(&QueryValidatorsRequest {
+                status: String::new(),
+                pagination: None,
+            })
+            .unwrap()
+            .validators[0]
+            .operator_address
+            .clone();
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: acc.address(),
+                    validator_address: validator.clone(),
+                    amount: Some(Coin {
+                        denom: "uaura".to_string(),
+                        amount: "50000000000".to_string(),
+                    }),
+                },
+                &acc,
+            )
+            .unwrap();
+        distribution
+            .set_withdraw_address(
+                MsgSetWithdrawAddress {
+                    delegator_address: acc.address(),
+                    withdraw_address: withdrawer.address(),
+                },
+                &acc,
+            )
+            .unwrap();
+
+        let rewards = |distribution: &Distribution<AuraTestApp>| {
+            let res = distribution
+                .query_delegation_rewards(&QueryDelegationRewardsRequest {
+                    delegator_address: acc.address(),
+                    validator_address: validator.clone(),
+                })
+                .unwrap();
+            dec_uaura(res.rewards)
+        };
+
+        // rewards accrue with each block
+        app.skip_blocks(10).unwrap();
+        let first = rewards(&distribution);
+        assert!(first > 0);
+        app.skip_blocks(10).unwrap();
+        let accrued = rewards(&distribution);
+        assert!(accrued > first);
+
+        // withdrawing moves them to the withdraw address, not the delegator
+        let delegator_before = bank.balance(&acc.address(), "uaura").unwrap().u128();
+        distribution
+            .withdraw_delegator_reward(
+                MsgWithdrawDelegatorReward {
+                    delegator_address: acc.address(),
+                    validator_address: validator.clone(),
+                },
+                &acc,
+            )
+            .unwrap();
+        let withdrawn = bank.balance(&withdrawer.address(), "uaura").unwrap().u128() - 1;
+        assert!(withdrawn >= accrued);
+        assert!(bank.balance(&acc.address(), "uaura").unwrap().u128() < delegator_before);
+        assert!(rewards(&distribution) < accrued);
+    }
+
+    fn community_pool_uaura(distribution: &Distribution<AuraTestApp>) -> u128 {
+        dec_uaura(
+            distribution
+                .query_community_pool(&QueryCommunityPoolRequest {})
+                .unwrap()
+                .pool,
+        )
+    }
+
+    /// Whole uaura in decimal coins, whose amounts carry 18 decimals.
+    fn dec_uaura(coins: Vec<DecCoin>) -> u128 {
+        coins
+            .into_iter()
+            .find(|c| c.denom == "uaura")
+            .map(|c| c.amount.parse::<u128>().unwrap() / 10u128.pow(18))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,279 @@
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmos_sdk_proto::cosmos::feegrant::v1beta1::{
+    AllowedMsgAllowance, BasicAllowance, MsgGrantAllowance, MsgGrantAllowanceResponse,
+    MsgRevokeAllowance, MsgRevokeAllowanceResponse, PeriodicAllowance, QueryAllowanceRequest,
+    QueryAllowanceResponse, QueryAllowancesByGranterRequest, QueryAllowancesByGranterResponse,
+    QueryAllowancesRequest, QueryAllowancesResponse,
+};
+use cosmos_sdk_proto::Any;
+use cosmwasm_std::Coin;
+use test_tube::{fn_execute, fn_query};
+
+use test_tube::account::{Account, SigningAccount};
+use test_tube::module::Module;
+use test_tube::runner::result::RunnerExecuteResult;
+use test_tube::runner::Runner;
+
+use crate::module::{coin_to_proto, to_any};
+
+pub struct FeeGrant<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for FeeGrant<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> FeeGrant<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub grant_allowance: MsgGrantAllowance["/cosmos.feegrant.v1beta1.MsgGrantAllowance"] => MsgGrantAllowanceResponse
+    }
+
+    fn_execute! {
+        pub revoke_allowance: MsgRevokeAllowance["/cosmos.feegrant.v1beta1.MsgRevokeAllowance"] => MsgRevokeAllowanceResponse
+    }
+
+    fn_query! {
+        pub query_allowance ["/cosmos.feegrant.v1beta1.Query/Allowance"]: QueryAllowanceRequest => QueryAllowanceResponse
+    }
+
+    fn_query! {
+        pub query_allowances ["/cosmos.feegrant.v1beta1.Query/Allowances"]: QueryAllowancesRequest => QueryAllowancesResponse
+    }
+
+    fn_query! {
+        pub query_allowances_by_granter ["/cosmos.feegrant.v1beta1.Query/AllowancesByGranter"]: QueryAllowancesByGranterRequest => QueryAllowancesByGranterResponse
+    }
+
+    /// Let `grantee` pay fees from the signer's balance, up to `spend_limit`
+    /// or without limit if empty. The allowance never expires.
+    pub fn grant_basic_allowance(
+        &self,
+        grantee: &str,
+        spend_limit: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantAllowanceResponse> {
+        let allowance = basic_allowance(spend_limit);
+
+        self.grant(
+            grantee,
+            to_any("/cosmos.feegrant.v1beta1.BasicAllowance", allowance)?,
+            signer,
+        )
+    }
+
+    /// Let `grantee` pay up to `period_spend_limit` in fees every `period_seconds`,
+    /// and up to `spend_limit` in total or without limit if empty.
+    pub fn grant_periodic_allowance(
+        &self,
+        grantee: &str,
+        spend_limit: &[Coin],
+        period_seconds: i64,
+        period_spend_limit: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantAllowanceResponse> {
+        let period_spend_limit: Vec<ProtoCoin> = period_spend_limit.iter().map(coin_to_proto).collect();
+        let mut allowance = PeriodicAllowance {
+            basic: Some(basic_allowance(spend_limit)),
+            period_spend_limit: period_spend_limit.clone(),
+            period_can_spend: period_spend_limit,
+            ..Default::default()
+        };
+        allowance.period.get_or_insert_with(Default::default).seconds = period_seconds;
+
+        self.grant(
+            grantee,
+            to_any("/cosmos.feegrant.v1beta1.PeriodicAllowance", allowance)?,
+            signer,
+        )
+    }
+
+    /// Same as `grant_basic_allowance`, but only for txs made of `allowed_messages` type urls.
+    pub fn grant_allowed_msg_allowance(
+        &self,
+        grantee: &str,
+        spend_limit: &[Coin],
+        allowed_messages: &[&str],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantAllowanceResponse> {
+        let allowance = AllowedMsgAllowance {
+            allowance: Some(to_any(
+                "/cosmos.feegrant.v1beta1.BasicAllowance",
+                basic_allowance(spend_limit),
+            )?),
+            allowed_messages: allowed_messages.iter().map(|m| m.to_string()).collect(),
+        };
+
+        self.grant(
+            grantee,
+            to_any("/cosmos.feegrant.v1beta1.AllowedMsgAllowance", allowance)?,
+            signer,
+        )
+    }
+
+    fn grant(
+        &self,
+        grantee: &str,
+        allowance: Any,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgGrantAllowanceResponse> {
+        self.grant_allowance(
+            MsgGrantAllowance {
+                granter: signer.address(),
+                grantee: grantee.to_owned(),
+                allowance: Some(allowance),
+            },
+            signer,
+        )
+    }
+}
+
+fn basic_allowance(spend_limit: &[Coin]) -> BasicAllowance {
+    BasicAllowance {
+        spend_limit: spend_limit.iter().map(coin_to_proto).collect(),
+        expiration: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::feegrant::v1beta1::{
+        BasicAllowance, MsgRevokeAllowance, QueryAllowanceRequest,
+    };
+    use cosmos_sdk_proto::traits::Message;
+    use cosmwasm_std::{coin, coins, Uint128};
+
+    use crate::{Account, AuraTestApp, Bank, FeeGrant, FeeSetting, Module};
+
+    #[test]
+    fn test_granted_fees() {
+        let app = AuraTestApp::default();
+        let feegrant = FeeGrant::new(&app);
+        let bank = Bank::new(&app);
+
+        let granter = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        // holds no fee denom at all
+        let grantee = app.init_base_account(&coins(10, "uatom")).unwrap();
+
+        feegrant
+            .grant_basic_allowance(&grantee.address(), &coins(10_000_000, "uaura"), &granter)
+            .unwrap();
+
+        // fees can't be paid without the granter
+        assert!(bank
+            .send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .is_err());
+
+        let grantee = grantee.with_fee_granter(&granter.address());
+        bank.send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .unwrap();
+        assert_eq!(bank.balance(&grantee.address(), "uatom").unwrap(), Uint128::new(9));
+
+        let grant = feegrant
+            .query_allowance(&QueryAllowanceRequest {
+                granter: granter.address(),
+                grantee: grantee.address(),
+            })
+            .unwrap()
+            .allowance
+            .unwrap();
+        let allowance = BasicAllowance::decode(grant.allowance.unwrap().value.as_slice()).unwrap();
+        let remaining: u128 = allowance.spend_limit[0].amount.parse().unwrap();
+        assert!(remaining < 10_000_000);
+
+        feegrant
+            .revoke_allowance(
+                MsgRevokeAllowance {
+                    granter: granter.address(),
+                    grantee: grantee.address(),
+                },
+                &granter,
+            )
+            .unwrap();
+        assert!(bank
+            .send_coins(&granter.address(), &[coin(1, "uatom")], &grantee)
+            .is_err());
+    }
+
+    #[test]
+    fn test_periodic_allowance_resets() {
+        let app = AuraTestApp::default();
+        let feegrant = FeeGrant::new(&app);
+        let bank = Bank::new(&app);
+
+        let granter = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        // a fixed fee, so one tx spends the whole period limit
+        let grantee = app
+            .init_base_account(&coins(10, "uatom"))
+            .unwrap()
+            .with_fee_setting(FeeSetting::Custom {
+                amount: coin(5_000, "uaura"),
+                gas_limit: 200_000,
+            });
+
+        feegrant
+            .grant_periodic_allowance(
+                &grantee.address(),
+                &[],
+                60,
+                &coins(5_000, "uaura"),
+                &granter,
+            )
+            .unwrap();
+
+        let grantee = grantee.with_fee_granter(&granter.address());
+        bank.send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .unwrap();
+
+        // the period limit is spent
+        assert!(bank
+            .send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .is_err());
+
+        // and available again once the period is over
+        app.skip_time(61).unwrap();
+        bank.send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .unwrap();
+        assert_eq!(bank.balance(&grantee.address(), "uatom").unwrap(), Uint128::new(8));
+    }
+
+    #[test]
+    fn test_allowed_msg_allowance_rejects_other_msgs() {
+        let app = AuraTestApp::default();
+        let feegrant = FeeGrant::new(&app);
+        let bank = Bank::new(&app);
+
+        let granter = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let grantee = app.init_base_account(&coins(10, "uatom")).unwrap();
+        let other = app.init_base_account(&[]).unwrap();
+
+        feegrant
+            .grant_allowed_msg_allowance(
+                &grantee.address(),
+                &coins(10_000_000, "uaura"),
+                &["/cosmos.bank.v1beta1.MsgSend"],
+                &granter,
+            )
+            .unwrap();
+
+        let grantee = grantee.with_fee_granter(&granter.address());
+        bank.send_coins(&granter.address(), &coins(1, "uatom"), &grantee)
+            .unwrap();
+
+        // MsgGrantAllowance is not on the list
+        assert!(feegrant
+            .grant_basic_allowance(&other.address(), &[], &grantee)
+            .is_err());
+        assert!(feegrant
+            .query_allowance(&QueryAllowanceRequest {
+                granter: grantee.address(),
+                grantee: other.address(),
+            })
+            .is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use cosmos_sdk_proto::cosmos::gov::v1::{
+    MsgDeposit, MsgDepositResponse, MsgSubmitProposalResponse, MsgVote, MsgVoteResponse, Proposal,
+    ProposalStatus, QueryDepositRequest, QueryDepositResponse, QueryDepositsRequest,
+    QueryDepositsResponse, QueryParamsRequest, QueryProposalRequest, QueryProposalResponse,
+    QueryProposalsRequest, QueryProposalsResponse, QueryTallyResultRequest,
+    QueryTallyResultResponse, QueryVoteRequest, QueryVoteResponse, QueryVotesRequest,
+    QueryVotesResponse, VoteOption,
+};
+use cosmos_sdk_proto::Any;
+use test_tube::{fn_execute, fn_query};
+
+use test_tube::account::{Account, SigningAccount};
+use test_tube::module::Module;
+use test_tube::runner::error::RunnerError;
+use test_tube::runner::result::RunnerResult;
+use test_tube::runner::Runner;
+
+use crate::proto::cosmos::gov::v1::{MsgSubmitProposal, QueryParamsResponse};
+use crate::AuraTestApp;
+
+/// Address of the gov module, the authority expected by param update messages.
+pub const GOV_MODULE_ADDRESS: &str = "aura10d07y265gmmuvt4z0w9aw880jnsr700jp5y852";
+
+pub struct Gov<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Gov<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Gov<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub submit_proposal: MsgSubmitProposal["/cosmos.gov.v1.MsgSubmitProposal"] => MsgSubmitProposalResponse
+    }
+
+    fn_execute! {
+        pub deposit: MsgDeposit["/cosmos.gov.v1.MsgDeposit"] => MsgDepositResponse
+    }
+
+    fn_execute! {
+        pub vote: MsgVote["/cosmos.gov.v1.MsgVote"] => MsgVoteResponse
+    }
+
+    fn_query! {
+        pub query_proposal ["/cosmos.gov.v1.Query/Proposal"]: QueryProposalRequest => QueryProposalResponse
+    }
+
+    fn_query! {
+        pub query_proposals ["/cosmos.gov.v1.Query/Proposals"]: QueryProposalsRequest => QueryProposalsResponse
+    }
+
+    fn_query! {
+        pub query_vote ["/cosmos.gov.v1.Query/Vote"]: QueryVoteRequest => QueryVoteResponse
+    }
+
+    fn_query! {
+        pub query_votes ["/cosmos.gov.v1.Query/Votes"]: QueryVotesRequest => QueryVotesResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.gov.v1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_deposit ["/cosmos.gov.v1.Query/Deposit"]: QueryDepositRequest => QueryDepositResponse
+    }
+
+    fn_query! {
+        pub query_deposits ["/cosmos.gov.v1.Query/Deposits"]: QueryDepositsRequest => QueryDepositsResponse
+    }
+
+    fn_query! {
+        pub query_tally_result ["/cosmos.gov.v1.Query/TallyResult"]: QueryTallyResultRequest => QueryTallyResultResponse
+    }
+}
+
+impl<'a> Gov<'a, AuraTestApp> {
+    /// Skip past the voting period and run a block, so proposals
+    /// in voting period are tallied and, if passed, executed.
+    pub fn skip_voting_period(&self) -> RunnerResult<()> {
+        let voting_period = self
+            .query_params(&QueryParamsRequest {
+                params_type: "voting".to_string(),
+            })?
+            .params
+            .and_then(|p| p.voting_period)
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: "gov params have no voting period".to_string(),
+            })?;
+
+        self.runner.skip_time(voting_period.seconds)?;
+        self.runner.skip_blocks(1)
+    }
+
+    /// Submit a proposal executing `messages` with the minimum deposit, vote yes
+    /// with the proposer and skip the voting period.
+    ///
+    /// The proposer needs the minimum deposit and enough bonded stake to reach quorum,
+    /// so delegate to the genesis validator first. Messages must use
+    /// `GOV_MODULE_ADDRESS` as their authority. Fails unless the proposal passed.
+    pub fn execute_proposal(
+        &self,
+        messages: Vec<Any>,
+        proposer: &SigningAccount,
+    ) -> RunnerResult<Proposal> {
+        let min_deposit = self
+            .query_params(&QueryParamsRequest {
+                params_type: "deposit".to_string(),
+            })?
+            .params
+            .map(|p| p.min_deposit)
+            .unwrap_or_default();
+
+        let proposal_id = self
+            .submit_proposal(
+                MsgSubmitProposal {
+                    messages,
+                    initial_deposit: min_deposit,
+                    proposer: proposer.address(),
+                    title: "execute proposal".to_string(),
+                    summary: "executes the proposal messages".to_string(),
+                    ..Default::default()
+                },
+                proposer,
+            )?
+            .data
+            .proposal_id;
+
+        self.vote(
+            MsgVote {
+                proposal_id,
+                voter: proposer.address(),
+                option: VoteOption::Yes.into(),
+                ..Default::default()
+            },
+            proposer,
+        )?;
+
+        self.skip_voting_period()?;
+
+        let proposal = self
+            .query_proposal(&QueryProposalRequest { proposal_id })?
+            .proposal
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("proposal {} not found", proposal_id),
+            })?;
+
+        if proposal.status != ProposalStatus::Passed as i32 {
+            return Err(RunnerError::ExecuteError {
+                msg: format!(
+                    "proposal {} ended with status {}, tally {:?}",
+                    proposal_id,
+                    ProposalStatus::try_from(proposal.status)
+                        .map(|s| s.as_str_name())
+                        .unwrap_or("unknown"),
+                    proposal.final_tally_result
+                ),
+            });
+        }
+
+        Ok(proposal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::gov::v1::QueryParamsRequest;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgDelegate, QueryValidatorsRequest};
+    use cosmos_sdk_proto::cosmwasm::wasm::v1::{AccessConfig, AccessType, Params};
+    use cosmos_sdk_proto::traits::MessageExt;
+    use cosmos_sdk_proto::Any;
+    use cosmwasm_std::{coin, Coin as CwCoin};
+
+    use super::GOV_MODULE_ADDRESS;
+    use crate::proto::cosmwasm::wasm::v1::MsgUpdateParams;
+    use crate::{Account, ArtifactRegistry, AuraTestApp, Gov, Module, Staking};
+
+    #[test]
+    fn test_execute_proposal() {
+        let app = AuraTestApp::default();
+        let gov = Gov::new(&app);
+        let staking = Staking::new(&app);
+
+        let min_deposit = gov
+            .query_params(&QueryParamsRequest {
+                params_type: "deposit".to_string(),
+            })
+            .unwrap()
+            .params
+            .unwrap()
+            .min_deposit;
+        let min_deposit: Vec<CwCoin> = min_deposit
+            .iter()
+            .map(|c| coin(c.amount.parse().unwrap(), &c.denom))
+            .collect();
+        let acc = app.init_base_account(&[coin(100_000_000_000, "uaura")]).unwrap();
+        app.fund_account(&acc.address(), &min_deposit).unwrap();
+
+        // voting power outweighs the genesis validator's self bond
+        let validator = staking
+            .query_validators(&QueryValidatorsRequest::default())
+            .unwrap()
+            .validators[0]
+            .operator_address
+            .clone();
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: acc.address(),
+                    validator_address: validator,
+                    amount: Some(Coin {
+                        denom: "uaura".to_string(),
+                        amount: "10000000000".to_string(),
+                    }),
+                },
+                &acc,
+            )
+            .unwrap();
+
+        // restrict wasm uploads through governance
+        let msg = MsgUpdateParams {
+            authority: GOV_MODULE_ADDRESS.to_string(),
+            params: Some(Params {
+                code_upload_access: Some(AccessConfig {
+                    permission: AccessType::Nobody.into(),
+                    ..Default::default()
+                }),
+                instantiate_default_permission: AccessType::Everybody.into(),
+            }),
+        };
+        let msg = Any {
+            type_url: "/cosmwasm.wasm.v1.MsgUpdateParams".to_string(),
+            value: msg.to_bytes().unwrap(),
+        };
+        gov.execute_proposal(vec![msg], &acc).unwrap();
+
+        let err = ArtifactRegistry::new(&app)
+            .unwrap()
+            .store_code("listener", &acc)
+            .unwrap_err();
+        assert!(err.to_string().contains("unauthorized"));
+    }
+}
@@ -1,11 +1,46 @@
+mod auth;
+mod authz;
 mod bank;
+mod contract;
+mod distribution;
+mod feegrant;
+mod gov;
 mod smartaccount;
+mod staking;
 mod wasm;
 
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmos_sdk_proto::traits::MessageExt;
+use cosmos_sdk_proto::Any;
+use cosmwasm_std::Coin;
+use test_tube::runner::error::EncodeError;
+use test_tube::runner::result::RunnerResult;
+
 pub use test_tube::macros;
 pub use test_tube::module::Module;
 
+pub use auth::{Auth, AuthAccount};
+pub use authz::Authz;
 pub use bank::Bank;
+pub use contract::Contract;
+pub use distribution::Distribution;
+pub use feegrant::FeeGrant;
+pub use gov::{Gov, GOV_MODULE_ADDRESS};
 pub use wasm::Wasm;
-pub use smartaccount::SmartAccount;
+pub use smartaccount::{recovery_credentials, SmartAccount};
+pub use staking::Staking;
+
+pub(crate) fn coin_to_proto(coin: &Coin) -> ProtoCoin {
+    ProtoCoin {
+        denom: coin.denom.clone(),
+        amount: coin.amount.to_string(),
+    }
+}
 
+/// Encodes `msg` as an `Any` of `type_url`, e.g. for authorizations and allowances.
+pub(crate) fn to_any<M: MessageExt>(type_url: &str, msg: M) -> RunnerResult<Any> {
+    Ok(Any {
+        type_url: type_url.to_owned(),
+        value: msg.to_bytes().map_err(EncodeError::ProtoEncodeError)?,
+    })
+}
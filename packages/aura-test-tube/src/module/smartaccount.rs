@@ -1,15 +1,24 @@
 use aura_proto::types::smartaccount::v1beta1::{
-    MsgActivateAccountRequest, MsgActivateAccountResponse, 
+    CodeId, MsgActivateAccount, MsgActivateAccountResponse, 
     QueryGenerateAccountRequest, QueryGenerateAccountResponse,
     QueryParamsRequest, QueryParamsResponse,
-    MsgRecoverRequest, MsgRecoverResponse
+    MsgRecover, MsgRecoverResponse
 };
+use cosmos_sdk_proto::cosmos::crypto::secp256k1::PubKey;
+use cosmos_sdk_proto::traits::MessageExt;
+use cosmrs::crypto::PublicKey;
+use cosmwasm_std::Coin;
+use serde::Serialize;
+use serde_json::json;
+use test_tube::runner::error::{EncodeError, RunnerError};
 use test_tube::runner::result::{RunnerExecuteResult, RunnerResult};
 use test_tube::{
     account::{Account, SigningAccount},
     runner::Runner,
 };
 
+use crate::{init_local_smart_account, AuraTestApp, SmartAccountSigner};
+
 pub struct SmartAccount<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -32,17 +41,37 @@ where
         signer: &SigningAccount,
     ) -> RunnerExecuteResult<MsgRecoverResponse> {
         self.runner.execute(
-            MsgRecoverRequest {
+            MsgRecover {
                 creator: signer.address(),
                 address,
                 public_key: Some(public_key),
                 credentials
             },
-            "/aura.smartaccount.v1beta1.MsgRecover",
+            MsgRecover::TYPE_URL,
             signer,
         )
     }
 
+    /// Rotate the key of a smart account backed by the reference `recovery` contract
+    /// to `new_pub_key`, with credentials signed by `guardian`, who also signs the tx.
+    /// `nonce` is the one returned by the contract's `nonce` query.
+    pub fn recover_with_guardian(
+        &self,
+        address: &str,
+        new_pub_key: &PublicKey,
+        nonce: u64,
+        guardian: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgRecoverResponse> {
+        let credentials = recovery_credentials(address, new_pub_key, nonce, guardian)?;
+
+        self.recover(
+            address.to_owned(),
+            pub_key_any(new_pub_key)?,
+            credentials,
+            guardian,
+        )
+    }
+
     pub fn activate_account(
         &self,
         code_id: u64,
@@ -53,14 +82,14 @@ where
     ) -> RunnerExecuteResult<MsgActivateAccountResponse>
     {
         self.runner.execute(
-            MsgActivateAccountRequest {
+            MsgActivateAccount {
                 account_address: signer.address(),
                 code_id,
                 salt,
                 init_msg,
                 pub_key: Some(pub_key),
             },
-            "/aura.smartaccount.v1beta1.MsgActivateAccount",
+            MsgActivateAccount::TYPE_URL,
             signer,
         )
     }
@@ -100,3 +129,148 @@ where
         Ok(res)
     }
 }
+
+/// `MsgRecover` credentials for the reference `recovery` contract: the guardian's
+/// signature allowing `account` to rotate its key to `new_pub_key` at `nonce`.
+pub fn recovery_credentials(
+    account: &str,
+    new_pub_key: &PublicKey,
+    nonce: u64,
+    guardian: &SigningAccount,
+) -> RunnerResult<String> {
+    // must match `recovery_payload` of the contract
+    let payload = format!(
+        "recover {} {} {}",
+        account,
+        nonce,
+        base64::encode(new_pub_key.to_bytes())
+    );
+    let signature = guardian
+        .signing_key()
+        .sign(payload.as_bytes())
+        .map_err(|e| RunnerError::ExecuteError {
+            msg: format!("unable to sign recovery credentials: {}", e),
+        })?;
+
+    let credentials = json!({ "signature": base64::encode(signature) });
+    let credentials = serde_json::to_vec(&credentials).map_err(EncodeError::JsonEncodeError)?;
+
+    Ok(base64::encode(credentials))
+}
+
+fn pub_key_any(pub_key: &PublicKey) -> RunnerResult<aura_proto::shim::Any> {
+    Ok(aura_proto::shim::Any {
+        type_url: String::from("/cosmos.crypto.secp256k1.PubKey"),
+        value: PubKey {
+            key: pub_key.to_bytes(),
+        }
+        .to_bytes()
+        .map_err(EncodeError::ProtoEncodeError)?,
+    })
+}
+
+impl<'a> SmartAccount<'a, AuraTestApp> {
+    /// Create a smart account backed by `code_id`, controlled by the `owner` key.
+    ///
+    /// Whitelists the code id, funds the generated address with `funds`, which
+    /// must cover the activation fee, and activates it. Returns the signer of the
+    /// smart account, using the app's default fee setting, and its address.
+    pub fn create<M: Serialize>(
+        &self,
+        code_id: u64,
+        init_msg: &M,
+        salt: &[u8],
+        owner: &SigningAccount,
+        funds: &[Coin],
+    ) -> RunnerResult<(SmartAccountSigner, String)> {
+        self.whitelist_code_id(code_id)?;
+
+        let init_msg = serde_json::to_vec(init_msg).map_err(EncodeError::JsonEncodeError)?;
+        let pub_key = pub_key_any(&owner.public_key())?;
+
+        let address = self.query_generate_account(
+            code_id,
+            salt.to_vec(),
+            init_msg.clone(),
+            pub_key.clone(),
+        )?;
+        if !funds.is_empty() {
+            self.runner.fund_account(&address, funds)?;
+        }
+
+        let signer = init_local_smart_account(address.clone(), owner.private_key())?;
+        self.activate_account(code_id, salt.to_vec(), init_msg, pub_key, &signer)?;
+
+        // activated, so fees can now be estimated by simulation
+        let signer = signer.with_fee_setting(self.runner.default_fee_setting().clone());
+
+        Ok((signer, address))
+    }
+
+    /// Allow `code_id` to back smart accounts, keeping the other params.
+    pub fn whitelist_code_id(&self, code_id: u64) -> RunnerResult<()> {
+        let mut params = self.query_params()?.params.unwrap_or_default();
+        if params
+            .whitelist_code_id
+            .iter()
+            .any(|c| c.code_id == code_id && c.status)
+        {
+            return Ok(());
+        }
+
+        params.whitelist_code_id.retain(|c| c.code_id != code_id);
+        params.whitelist_code_id.push(CodeId {
+            code_id,
+            status: true,
+        });
+        self.runner
+            .set_param_set("smartaccount", params.to_any().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, Uint128};
+    use serde_json::json;
+
+    use crate::{Account, ArtifactRegistry, AuraTestApp, Auth, AuthAccount, Bank, Module, SmartAccount};
+
+    #[test]
+    fn test_create() {
+        let app = AuraTestApp::default();
+        let smartaccount = SmartAccount::new(&app);
+        let bank = Bank::new(&app);
+        let owner = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        let code_id = ArtifactRegistry::new(&app)
+            .unwrap()
+            .store_code("spend-limit", &owner)
+            .unwrap();
+        let init_msg = json!({ "limit": { "denom": "uaura", "amount": "10000" } });
+
+        let (signer, address) = smartaccount
+            .create(code_id, &init_msg, b"salt", &owner, &coins(10_000_000, "uaura"))
+            .unwrap();
+        assert_eq!(signer.address(), address);
+        assert_eq!(signer.account_id().to_string(), address);
+        assert_ne!(
+            signer.public_key().account_id("aura").unwrap().to_string(),
+            address
+        );
+        assert_ne!(signer.signing_account().account_id().to_string(), address);
+        assert!(matches!(
+            Auth::new(&app).account(&address).unwrap(),
+            AuthAccount::Smart(_)
+        ));
+
+        bank.send_coins(&owner.address(), &coins(1_000, "uaura"), &signer)
+            .unwrap();
+        let balance = bank.balance(&address, "uaura").unwrap();
+        assert!(balance < Uint128::new(10_000_000 - 1_000));
+
+        // the same salt gives the same, already activated, address
+        assert!(smartaccount
+            .create(code_id, &init_msg, b"salt", &owner, &[])
+            .is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+    MsgBeginRedelegate, MsgBeginRedelegateResponse, MsgCancelUnbondingDelegation,
+    MsgCancelUnbondingDelegationResponse, MsgCreateValidator, MsgCreateValidatorResponse,
+    MsgDelegate, MsgDelegateResponse, MsgEditValidator, MsgEditValidatorResponse, MsgUndelegate,
+    MsgUndelegateResponse, QueryDelegationRequest, QueryDelegationResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
+    QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse, QueryParamsRequest,
+    QueryParamsResponse, QueryPoolRequest, QueryPoolResponse, QueryRedelegationsRequest,
+    QueryRedelegationsResponse, QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorUnbondingDelegationsRequest,
+    QueryValidatorUnbondingDelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+};
+use test_tube::{fn_execute, fn_query};
+
+use test_tube::module::Module;
+use test_tube::runner::Runner;
+
+pub struct Staking<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Staking<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Staking<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub create_validator: MsgCreateValidator["/cosmos.staking.v1beta1.MsgCreateValidator"] => MsgCreateValidatorResponse
+    }
+
+    fn_execute! {
+        pub edit_validator: MsgEditValidator["/cosmos.staking.v1beta1.MsgEditValidator"] => MsgEditValidatorResponse
+    }
+
+    fn_execute! {
+        pub delegate: MsgDelegate["/cosmos.staking.v1beta1.MsgDelegate"] => MsgDelegateResponse
+    }
+
+    fn_execute! {
+        pub undelegate: MsgUndelegate["/cosmos.staking.v1beta1.MsgUndelegate"] => MsgUndelegateResponse
+    }
+
+    fn_execute! {
+        pub redelegate: MsgBeginRedelegate["/cosmos.staking.v1beta1.MsgBeginRedelegate"] => MsgBeginRedelegateResponse
+    }
+
+    fn_execute! {
+        pub cancel_unbonding_delegation: MsgCancelUnbondingDelegation["/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation"] => MsgCancelUnbondingDelegationResponse
+    }
+
+    fn_query! {
+        pub query_validators ["/cosmos.staking.v1beta1.Query/Validators"]: QueryValidatorsRequest => QueryValidatorsResponse
+    }
+
+    fn_query! {
+        pub query_validator ["/cosmos.staking.v1beta1.Query/Validator"]: QueryValidatorRequest => QueryValidatorResponse
+    }
+
+    fn_query! {
+        pub query_validator_delegations ["/cosmos.staking.v1beta1.Query/ValidatorDelegations"]: QueryValidatorDelegationsRequest => QueryValidatorDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_validator_unbonding_delegations ["/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations"]: QueryValidatorUnbondingDelegationsRequest => QueryValidatorUnbondingDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegation ["/cosmos.staking.v1beta1.Query/Delegation"]: QueryDelegationRequest => QueryDelegationResponse
+    }
+
+    fn_query! {
+        pub query_unbonding_delegation ["/cosmos.staking.v1beta1.Query/UnbondingDelegation"]: QueryUnbondingDelegationRequest => QueryUnbondingDelegationResponse
+    }
+
+    fn_query! {
+        pub query_delegator_delegations ["/cosmos.staking.v1beta1.Query/DelegatorDelegations"]: QueryDelegatorDelegationsRequest => QueryDelegatorDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_unbonding_delegations ["/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations"]: QueryDelegatorUnbondingDelegationsRequest => QueryDelegatorUnbondingDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_redelegations ["/cosmos.staking.v1beta1.Query/Redelegations"]: QueryRedelegationsRequest => QueryRedelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_validators ["/cosmos.staking.v1beta1.Query/DelegatorValidators"]: QueryDelegatorValidatorsRequest => QueryDelegatorValidatorsResponse
+    }
+
+    fn_query! {
+        pub query_pool ["/cosmos.staking.v1beta1.Query/Pool"]: QueryPoolRequest => QueryPoolResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.staking.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+        MsgDelegate, MsgUndelegate, QueryDelegationRequest, QueryUnbondingDelegationRequest,
+        QueryValidatorsRequest,
+    };
+    use cosmwasm_std::coins;
+
+    use crate::{Account, AuraTestApp, Module, Staking};
+
+    #[test]
+    fn test_delegate_and_undelegate() {
+        let app = AuraTestApp::default();
+        let staking = Staking::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+
+        // genesis has a single bonded validator
+        let validator = staking
+            .query_validators(&QueryValidatorsRequest {
+                status: String::new(),
+                pagination: None,
+            })
+            .unwrap()
+            .validators[0]
+            .operator_address
+            .clone();
+
+        let amount = |n: u128| Coin {
+            denom: "uaura".to_string(),
+            amount: n.to_string(),
+        };
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: acc.address(),
+                    validator_address: validator.clone(),
+                    amount: Some(amount(1_000_000)),
+                },
+                &acc,
+            )
+            .unwrap();
+
+        let delegation = staking
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: acc.address(),
+                validator_addr: validator.clone(),
+            })
+            .unwrap()
+            .delegation_response
+            .unwrap();
+        assert_eq!(delegation.balance, Some(amount(1_000_000)));
+
+        staking
+            .undelegate(
+                MsgUndelegate {
+                    delegator_address: acc.address(),
+                    validator_address: validator.clone(),
+                    amount: Some(amount(400_000)),
+                },
+                &acc,
+            )
+            .unwrap();
+
+        let unbond = staking
+            .query_unbonding_delegation(&QueryUnbondingDelegationRequest {
+                delegator_addr: acc.address(),
+                validator_addr: validator,
+            })
+            .unwrap()
+            .unbond
+            .unwrap();
+        assert_eq!(unbond.entries[0].balance, "400000");
+    }
+}
@@ -1,9 +1,20 @@
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmos_sdk_proto::cosmwasm::wasm::v1::{
-    AccessConfig, MsgExecuteContract, MsgExecuteContractResponse, MsgInstantiateContract,
+    AccessConfig, CodeInfoResponse, ContractInfo, MsgClearAdmin, MsgClearAdminResponse,
+    MsgExecuteContract, MsgExecuteContractResponse, MsgInstantiateContract,
     MsgInstantiateContractResponse, MsgInstantiateContract2, MsgInstantiateContract2Response,
-    MsgStoreCode, MsgStoreCodeResponse, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+    MsgMigrateContract, MsgMigrateContractResponse, MsgStoreCode, MsgStoreCodeResponse,
+    MsgUpdateAdmin, MsgUpdateAdminResponse, QueryAllContractStateRequest,
+    QueryAllContractStateResponse, QueryCodeRequest, QueryCodeResponse, QueryCodesRequest,
+    QueryCodesResponse, QueryContractHistoryRequest, QueryContractHistoryResponse,
+    QueryContractInfoRequest, QueryContractInfoResponse, QueryContractsByCodeRequest,
+    QueryContractsByCodeResponse, QueryPinnedCodesRequest, QueryPinnedCodesResponse,
+    QueryRawContractStateRequest, QueryRawContractStateResponse, QuerySmartContractStateRequest,
+    QuerySmartContractStateResponse,
 };
 use cosmwasm_std::Coin;
+use cw2::ContractVersion;
+use cw_storage_plus::{Item, Map, PrimaryKey};
 use serde::{de::DeserializeOwned, Serialize};
 
 use test_tube::runner::error::{DecodeError, EncodeError, RunnerError};
@@ -13,8 +24,11 @@ use test_tube::{
     runner::Runner,
 };
 
+use crate::proto::cosmwasm::wasm::v1::MsgSudoContractResponse;
+use crate::AuraTestApp;
+
 pub struct Wasm<'a, R: Runner<'a>> {
-    runner: &'a R,
+    pub(super) runner: &'a R,
 }
 
 impl<'a, R: Runner<'a>> super::Module<'a, R> for Wasm<'a, R> {
@@ -140,6 +154,60 @@ where
         )
     }
 
+    pub fn migrate<M>(
+        &self,
+        contract: &str,
+        new_code_id: u64,
+        msg: &M,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgMigrateContractResponse>
+    where
+        M: ?Sized + Serialize,
+    {
+        self.runner.execute(
+            MsgMigrateContract {
+                sender: signer.address(),
+                contract: contract.to_owned(),
+                code_id: new_code_id,
+                msg: serde_json::to_vec(msg).map_err(EncodeError::JsonEncodeError)?,
+            },
+            "/cosmwasm.wasm.v1.MsgMigrateContract",
+            signer,
+        )
+    }
+
+    pub fn update_admin(
+        &self,
+        contract: &str,
+        new_admin: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgUpdateAdminResponse> {
+        self.runner.execute(
+            MsgUpdateAdmin {
+                sender: signer.address(),
+                new_admin: new_admin.to_owned(),
+                contract: contract.to_owned(),
+            },
+            "/cosmwasm.wasm.v1.MsgUpdateAdmin",
+            signer,
+        )
+    }
+
+    pub fn clear_admin(
+        &self,
+        contract: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgClearAdminResponse> {
+        self.runner.execute(
+            MsgClearAdmin {
+                sender: signer.address(),
+                contract: contract.to_owned(),
+            },
+            "/cosmwasm.wasm.v1.MsgClearAdmin",
+            signer,
+        )
+    }
+
     pub fn query<M, Res>(&self, contract: &str, msg: &M) -> RunnerResult<Res>
     where
         M: ?Sized + Serialize,
@@ -159,4 +227,220 @@ where
             .map_err(DecodeError::JsonDecodeError)
             .map_err(RunnerError::DecodeError)
     }
+
+    pub fn query_contract_info(&self, contract: &str) -> RunnerResult<ContractInfo> {
+        let res = self
+            .runner
+            .query::<QueryContractInfoRequest, QueryContractInfoResponse>(
+                "/cosmwasm.wasm.v1.Query/ContractInfo",
+                &QueryContractInfoRequest {
+                    address: contract.to_owned(),
+                },
+            )?;
+
+        res.contract_info.ok_or_else(|| RunnerError::QueryError {
+            msg: format!("contract info not found for `{}`", contract),
+        })
+    }
+
+    /// Query the info of a stored code, without its wasm byte code.
+    pub fn query_code_info(&self, code_id: u64) -> RunnerResult<CodeInfoResponse> {
+        let res = self
+            .runner
+            .query::<QueryCodeRequest, QueryCodeResponse>(
+                "/cosmwasm.wasm.v1.Query/Code",
+                &QueryCodeRequest { code_id },
+            )?;
+
+        res.code_info.ok_or_else(|| RunnerError::QueryError {
+            msg: format!("code info not found for code id {}", code_id),
+        })
+    }
+
+    pub fn query_contracts_by_code(
+        &self,
+        code_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> RunnerResult<QueryContractsByCodeResponse> {
+        self.runner
+            .query::<QueryContractsByCodeRequest, QueryContractsByCodeResponse>(
+                "/cosmwasm.wasm.v1.Query/ContractsByCode",
+                &QueryContractsByCodeRequest {
+                    code_id,
+                    pagination,
+                },
+            )
+    }
+
+    pub fn query_contract_history(
+        &self,
+        contract: &str,
+        pagination: Option<PageRequest>,
+    ) -> RunnerResult<QueryContractHistoryResponse> {
+        self.runner
+            .query::<QueryContractHistoryRequest, QueryContractHistoryResponse>(
+                "/cosmwasm.wasm.v1.Query/ContractHistory",
+                &QueryContractHistoryRequest {
+                    address: contract.to_owned(),
+                    pagination,
+                },
+            )
+    }
+
+    pub fn query_codes(&self, pagination: Option<PageRequest>) -> RunnerResult<QueryCodesResponse> {
+        self.runner.query::<QueryCodesRequest, QueryCodesResponse>(
+            "/cosmwasm.wasm.v1.Query/Codes",
+            &QueryCodesRequest { pagination },
+        )
+    }
+
+    pub fn query_pinned_codes(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> RunnerResult<QueryPinnedCodesResponse> {
+        self.runner
+            .query::<QueryPinnedCodesRequest, QueryPinnedCodesResponse>(
+                "/cosmwasm.wasm.v1.Query/PinnedCodes",
+                &QueryPinnedCodesRequest { pagination },
+            )
+    }
+
+    /// Query the raw value stored under `key` in the contract's storage.
+    /// Returns empty bytes if the key does not exist.
+    pub fn query_raw(&self, contract: &str, key: &[u8]) -> RunnerResult<Vec<u8>> {
+        let res = self
+            .runner
+            .query::<QueryRawContractStateRequest, QueryRawContractStateResponse>(
+                "/cosmwasm.wasm.v1.Query/RawContractState",
+                &QueryRawContractStateRequest {
+                    address: contract.to_owned(),
+                    query_data: key.to_vec(),
+                },
+            )?;
+
+        Ok(res.data)
+    }
+
+    /// Query all raw key-value pairs in the contract's storage, page by page.
+    pub fn query_all_state(
+        &self,
+        contract: &str,
+        pagination: Option<PageRequest>,
+    ) -> RunnerResult<QueryAllContractStateResponse> {
+        self.runner
+            .query::<QueryAllContractStateRequest, QueryAllContractStateResponse>(
+                "/cosmwasm.wasm.v1.Query/AllContractState",
+                &QueryAllContractStateRequest {
+                    address: contract.to_owned(),
+                    pagination,
+                },
+            )
+    }
+
+    /// Read a cw-storage-plus `Item` from the contract's storage.
+    pub fn query_item<T>(&self, contract: &str, item: &Item<T>) -> RunnerResult<Option<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.query_raw_json(contract, item.as_slice())
+    }
+
+    /// Read the entry under `key` of a cw-storage-plus `Map` from the contract's storage.
+    pub fn query_map_entry<'k, K, T>(
+        &self,
+        contract: &str,
+        map: &Map<'k, K, T>,
+        key: K,
+    ) -> RunnerResult<Option<T>>
+    where
+        K: PrimaryKey<'k>,
+        T: Serialize + DeserializeOwned,
+    {
+        self.query_raw_json(contract, &map.key(key))
+    }
+
+    /// Read the cw2 contract version set by `cw2::set_contract_version`.
+    pub fn query_contract_version(&self, contract: &str) -> RunnerResult<ContractVersion> {
+        self.query_item(contract, &cw2::CONTRACT)?
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("cw2 contract_info not found for `{}`", contract),
+            })
+    }
+
+    fn query_raw_json<T>(&self, contract: &str, key: &[u8]) -> RunnerResult<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let data = self.query_raw(contract, key)?;
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        serde_json::from_slice(&data)
+            .map(Some)
+            .map_err(DecodeError::JsonDecodeError)
+            .map_err(RunnerError::DecodeError)
+    }
+}
+
+impl<'a> Wasm<'a, AuraTestApp> {
+    /// Call the contract's `sudo` entry point directly, as a privileged module would.
+    /// No signer is involved, the call runs in its own block.
+    pub fn sudo<M>(&self, contract: &str, msg: &M) -> RunnerExecuteResult<MsgSudoContractResponse>
+    where
+        M: ?Sized + Serialize,
+    {
+        let msg = serde_json::to_vec(msg).map_err(EncodeError::JsonEncodeError)?;
+
+        self.runner.wasm_sudo(contract, &msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+    use serde::Serialize;
+
+    use crate::{Account, ArtifactRegistry, AuraTestApp, Module, Wasm};
+
+    #[derive(Serialize)]
+    struct EmptyMsg {}
+
+    #[test]
+    fn test_admin_handover_and_info_queries() {
+        let app = AuraTestApp::default();
+        let wasm = Wasm::new(&app);
+        let accs = app
+            .init_base_accounts(&coins(100_000_000_000, "uaura"), 2)
+            .unwrap();
+        let (owner, new_owner) = (&accs[0], &accs[1]);
+
+        let code_id = ArtifactRegistry::new(&app).unwrap().store_code("listener", owner).unwrap();
+        assert_eq!(wasm.query_code_info(code_id).unwrap().creator, owner.address());
+
+        let contract = wasm
+            .instantiate(code_id, &EmptyMsg {}, Some(&owner.address()), Some("listener"), &[], owner)
+            .unwrap()
+            .data
+            .address;
+        let contracts = wasm.query_contracts_by_code(code_id, None).unwrap().contracts;
+        assert_eq!(contracts, vec![contract.clone()]);
+
+        // only the admin can hand over the contract
+        assert!(wasm.update_admin(&contract, &new_owner.address(), new_owner).is_err());
+        wasm.update_admin(&contract, &new_owner.address(), owner).unwrap();
+        assert_eq!(wasm.query_contract_info(&contract).unwrap().admin, new_owner.address());
+
+        // migrate checks admin before calling into the contract
+        let err = wasm.migrate(&contract, code_id, &EmptyMsg {}, owner).unwrap_err();
+        assert!(err.to_string().contains("unauthorized"));
+
+        wasm.clear_admin(&contract, new_owner).unwrap();
+        assert_eq!(wasm.query_contract_info(&contract).unwrap().admin, "");
+
+        let history = wasm.query_contract_history(&contract, None).unwrap().entries;
+        assert_eq!(history.len(), 1);
+        assert_eq!(wasm.query_codes(None).unwrap().code_infos.len(), 1);
+        assert!(wasm.query_pinned_codes(None).unwrap().code_ids.is_empty());
+    }
 }
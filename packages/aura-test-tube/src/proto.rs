@@ -0,0 +1,141 @@
+//! Messages of the chain's cosmos-sdk v0.47 and wasmd modules missing from `cosmos-sdk-proto`,
+//! which is generated from cosmos-sdk v0.46 and wasmd v0.29. Laid out like `cosmos_sdk_proto`.
+
+pub mod cosmos {
+    pub mod bank {
+        pub mod v1beta1 {
+            use cosmos_sdk_proto::cosmos::bank::v1beta1::SendEnabled;
+            use cosmos_sdk_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+
+            /// Send enabled entries of `denoms`, or of all denoms with one if empty.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct QuerySendEnabledRequest {
+                #[prost(string, repeated, tag = "1")]
+                pub denoms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+                #[prost(message, optional, tag = "99")]
+                pub pagination: ::core::option::Option<PageRequest>,
+            }
+
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct QuerySendEnabledResponse {
+                #[prost(message, repeated, tag = "1")]
+                pub send_enabled: ::prost::alloc::vec::Vec<SendEnabled>,
+                #[prost(message, optional, tag = "99")]
+                pub pagination: ::core::option::Option<PageResponse>,
+            }
+        }
+    }
+    pub mod gov {
+        pub mod v1 {
+            use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+            use cosmos_sdk_proto::cosmos::gov::v1::{DepositParams, TallyParams, VotingParams};
+            use cosmos_sdk_proto::Any;
+
+            /// `MsgSubmitProposal` with the `title` and `summary` v0.47 requires.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct MsgSubmitProposal {
+                #[prost(message, repeated, tag = "1")]
+                pub messages: ::prost::alloc::vec::Vec<Any>,
+                #[prost(message, repeated, tag = "2")]
+                pub initial_deposit: ::prost::alloc::vec::Vec<Coin>,
+                #[prost(string, tag = "3")]
+                pub proposer: ::prost::alloc::string::String,
+                #[prost(string, tag = "4")]
+                pub metadata: ::prost::alloc::string::String,
+                #[prost(string, tag = "5")]
+                pub title: ::prost::alloc::string::String,
+                #[prost(string, tag = "6")]
+                pub summary: ::prost::alloc::string::String,
+            }
+
+            /// Gov params, replacing the deprecated per type params of `QueryParamsResponse`.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Params {
+                #[prost(message, repeated, tag = "1")]
+                pub min_deposit: ::prost::alloc::vec::Vec<Coin>,
+                #[prost(message, optional, tag = "2")]
+                pub max_deposit_period: ::core::option::Option<::prost_types::Duration>,
+                #[prost(message, optional, tag = "3")]
+                pub voting_period: ::core::option::Option<::prost_types::Duration>,
+                #[prost(string, tag = "4")]
+                pub quorum: ::prost::alloc::string::String,
+                #[prost(string, tag = "5")]
+                pub threshold: ::prost::alloc::string::String,
+                #[prost(string, tag = "6")]
+                pub veto_threshold: ::prost::alloc::string::String,
+                #[prost(string, tag = "7")]
+                pub min_initial_deposit_ratio: ::prost::alloc::string::String,
+                #[prost(bool, tag = "13")]
+                pub burn_vote_quorum: bool,
+                #[prost(bool, tag = "14")]
+                pub burn_proposal_deposit_prevote: bool,
+                #[prost(bool, tag = "15")]
+                pub burn_vote_veto: bool,
+            }
+
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct QueryParamsResponse {
+                #[prost(message, optional, tag = "1")]
+                pub voting_params: ::core::option::Option<VotingParams>,
+                #[prost(message, optional, tag = "2")]
+                pub deposit_params: ::core::option::Option<DepositParams>,
+                #[prost(message, optional, tag = "3")]
+                pub tally_params: ::core::option::Option<TallyParams>,
+                #[prost(message, optional, tag = "4")]
+                pub params: ::core::option::Option<Params>,
+            }
+        }
+    }
+}
+
+pub mod cosmwasm {
+    pub mod wasm {
+        pub mod v1 {
+            use cosmos_sdk_proto::cosmwasm::wasm::v1::Params;
+            use cosmos_sdk_proto::Any;
+
+            /// Authorization to execute the contracts of `grants`.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct ContractExecutionAuthorization {
+                #[prost(message, repeated, tag = "1")]
+                pub grants: ::prost::alloc::vec::Vec<ContractGrant>,
+            }
+
+            /// A contract with the `limit` and message `filter` its executions are checked against.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct ContractGrant {
+                #[prost(string, tag = "1")]
+                pub contract: ::prost::alloc::string::String,
+                #[prost(message, optional, tag = "2")]
+                pub limit: ::core::option::Option<Any>,
+                #[prost(message, optional, tag = "3")]
+                pub filter: ::core::option::Option<Any>,
+            }
+
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct MaxCallsLimit {
+                #[prost(uint64, tag = "1")]
+                pub remaining: u64,
+            }
+
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct AllowAllMessagesFilter {}
+
+            /// Update of the wasm params, executed by the gov module.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct MsgUpdateParams {
+                #[prost(string, tag = "1")]
+                pub authority: ::prost::alloc::string::String,
+                #[prost(message, optional, tag = "2")]
+                pub params: ::core::option::Option<Params>,
+            }
+
+            /// Response of a `sudo` call, `data` is set by the contract.
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct MsgSudoContractResponse {
+                #[prost(bytes = "vec", tag = "1")]
+                pub data: ::prost::alloc::vec::Vec<u8>,
+            }
+        }
+    }
+}
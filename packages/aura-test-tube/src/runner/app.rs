@@ -1,15 +1,20 @@
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmrs::Any;
 use cosmwasm_std::Coin;
 use prost::Message;
-use test_tube::account::SigningAccount;
+use test_tube::account::{FeeSetting, SigningAccount};
+use test_tube::runner::gas::{GasDiff, GasRecorder};
+use test_tube::runner::hooks::HookInvocation;
 use test_tube::runner::result::{RunnerExecuteResult, RunnerResult};
 use test_tube::runner::Runner;
 use test_tube::BaseApp;
 
 pub const FEE_DENOM: &str = "uaura";
 pub const CHAIN_ID: &str = "aura-testnet";
+pub const DEFAULT_GAS_PRICE: f64 = 0.025;
 pub const DEFAULT_GAS_ADJUSTMENT: f64 = 2.0;
 pub const DEFAULT_GAS_LIMIT: u64 = 20000000;
+pub const DEFAULT_HD_PATH: &str = "m/44'/118'/0'/0/0";
 
 #[derive(Debug, PartialEq)]
 pub struct AuraTestApp {
@@ -25,15 +30,69 @@ impl Default for AuraTestApp {
 impl AuraTestApp {
     pub fn new() -> Self {
         Self {
-            inner: BaseApp::new(FEE_DENOM, CHAIN_ID, DEFAULT_GAS_ADJUSTMENT, DEFAULT_GAS_LIMIT),
+            inner: BaseApp::new(
+                FEE_DENOM,
+                CHAIN_ID,
+                DEFAULT_GAS_PRICE,
+                DEFAULT_GAS_ADJUSTMENT,
+                DEFAULT_GAS_LIMIT,
+            ),
         }
     }
 
+    /// Replace the fee setting given to accounts created by this app.
+    /// By default, fees are estimated by simulation with `DEFAULT_GAS_PRICE`
+    /// and the gas limit is capped at `DEFAULT_GAS_LIMIT`.
+    pub fn with_default_fee_setting(self, fee_setting: FeeSetting) -> Self {
+        Self {
+            inner: self.inner.with_default_fee_setting(fee_setting),
+        }
+    }
+
+    pub fn default_fee_setting(&self) -> &FeeSetting {
+        self.inner.default_fee_setting()
+    }
+
+    /// Opt in to recording gas with `record_gas` and checking it with `write_gas_snapshot`.
+    pub fn with_gas_recorder(self, gas_recorder: GasRecorder) -> Self {
+        Self {
+            inner: self.inner.with_gas_recorder(gas_recorder),
+        }
+    }
+
+    pub fn gas_recorder(&self) -> Option<&GasRecorder> {
+        self.inner.gas_recorder()
+    }
+
+    /// Record gas used under `label`. Does nothing without a gas recorder.
+    pub fn record_gas(&self, label: &str, gas_info: &GasInfo) {
+        self.inner.record_gas(label, gas_info)
+    }
+
+    /// Compare recorded gas against the snapshot file and update it.
+    /// Returns no diffs without a gas recorder.
+    pub fn write_gas_snapshot(&self) -> RunnerResult<Vec<GasDiff>> {
+        self.inner.write_gas_snapshot()
+    }
+
+    /// Enforce minimum gas prices (e.g. `"0.025uaura"`) in the ante handler,
+    /// so that txs paying less than mainnet validators accept are rejected.
+    /// Pass an empty string to stop enforcing them.
+    /// Commits an empty block, so they apply from the next tx on.
+    pub fn set_min_gas_prices(&self, min_gas_prices: &str) -> RunnerResult<()> {
+        self.inner.set_min_gas_prices(min_gas_prices)
+    }
+
     // skip time in second
     pub fn skip_time(&self, skip_time: i64) -> RunnerResult<()> {
         self.inner.skip_time(skip_time)
     }
 
+    /// Run empty blocks, so that begin and end blockers take effect without a tx.
+    pub fn skip_blocks(&self, blocks: u64) -> RunnerResult<()> {
+        self.inner.skip_blocks(blocks)
+    }
+
     /// Initialize account with initial balance of any coins.
     /// This function mints new coins and send to newly created account
     pub fn init_base_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
@@ -44,6 +103,36 @@ impl AuraTestApp {
     pub fn init_base_accounts(&self, coins: &[Coin], count: u64) -> RunnerResult<Vec<SigningAccount>> {
         self.inner.init_base_accounts(coins, count)
     }
+
+    /// Initialize account from a BIP-39 mnemonic, derived at `hd_path`
+    /// (use `DEFAULT_HD_PATH` for the first cosmos account), with initial balance of any coins.
+    /// This function mints new coins and send to the derived account
+    pub fn init_account_from_mnemonic(
+        &self,
+        mnemonic: &str,
+        hd_path: &str,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        self.inner.init_account_from_mnemonic(mnemonic, hd_path, coins)
+    }
+
+    /// Initialize account from raw secp256k1 private key bytes
+    /// with initial balance of any coins.
+    /// This function mints new coins and send to the key's account
+    pub fn init_account_from_private_key(
+        &self,
+        private_key: &[u8],
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        self.inner.init_account_from_private_key(private_key, coins)
+    }
+
+    /// Mint coins and send them to any bech32 address.
+    /// Coins are sent without the bank blocked address check,
+    /// so contracts and module accounts can be funded too.
+    pub fn fund_account(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        self.inner.fund_account(address, coins)
+    }
     
     /// Simulate transaction execution and return gas info
     pub fn simulate_tx<I>(
@@ -62,6 +151,45 @@ impl AuraTestApp {
         self.inner.set_param_set(subspace, pset)
     }
 
+    // ===== cheatcodes =====
+    // These mutate chain state directly through the keepers, without any tx.
+
+    /// Mint or burn coins so that `address` holds exactly the given amount
+    /// of each denom in `coins`. Balances of other denoms are left untouched.
+    /// Like `fund_account`, module accounts can be set too.
+    pub fn set_balance(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        self.inner.set_balance(address, coins)
+    }
+
+    /// Overwrite a raw key in a contract's storage. An empty `value` removes the key.
+    pub fn set_contract_state(&self, contract: &str, key: &[u8], value: &[u8]) -> RunnerResult<()> {
+        self.inner.set_contract_state(contract, key, value)
+    }
+
+    /// Set the sequence of an existing account.
+    pub fn set_account_sequence(&self, address: &str, sequence: u64) -> RunnerResult<()> {
+        self.inner.set_account_sequence(address, sequence)
+    }
+
+    /// Set the admin of a contract, bypassing the current admin. `None` clears the admin.
+    pub fn set_contract_admin(&self, contract: &str, admin: Option<&str>) -> RunnerResult<()> {
+        self.inner.set_contract_admin(contract, admin)
+    }
+
+    /// Call the `sudo` entry point of a contract with a JSON encoded message,
+    /// as a privileged module would. State changes are discarded if the call fails.
+    pub fn wasm_sudo<R>(&self, contract: &str, msg: &[u8]) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.inner.wasm_sudo(contract, msg)
+    }
+
+    /// Smart account hooks called by the last executed tx, including a failed one.
+    pub fn hook_invocations(&self) -> RunnerResult<Vec<HookInvocation>> {
+        self.inner.hook_invocations()
+    }
+
     /// Get parameter set for a given subspace.
     pub fn get_param_set<P: Message + Default>(
         &self,
@@ -97,14 +225,239 @@ impl<'a> Runner<'a> for AuraTestApp {
 #[cfg(test)]
 mod tests {
     use std::option::Option::None;
-    use cosmwasm_std::coins;
-    use crate::runner::app::AuraTestApp;
-    use test_tube::account::Account;
+    use cosmwasm_std::{coins, Empty};
+    use crate::runner::app::{AuraTestApp, DEFAULT_HD_PATH};
+    use crate::{ArtifactRegistry, Auth, Module, Wasm};
+    use test_tube::account::{Account, FeeSetting};
+    use test_tube::runner::gas::GasRecorder;
+    use test_tube::runner::result::{ExecuteResponse, RunnerResult};
     use test_tube::runner::*;
 
     use cosmos_sdk_proto::cosmos::bank::v1beta1::{
-        QueryAllBalancesRequest, QueryAllBalancesResponse
+        MsgSend, MsgSendResponse, QueryAllBalancesRequest, QueryAllBalancesResponse
     };
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+
+    #[test]
+    fn test_min_gas_prices() {
+        let app = AuraTestApp::default();
+        app.set_min_gas_prices("0.025uaura").unwrap();
+
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let to = app.init_base_account(&coins(1, "uaura")).unwrap();
+        let msg = MsgSend {
+            from_address: acc.address(),
+            to_address: to.address(),
+            amount: vec![Coin {
+                denom: "uaura".to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+
+        // default fee setting pays the default gas price
+        let res: RunnerResult<ExecuteResponse<MsgSendResponse>> =
+            app.execute(msg.clone(), "/cosmos.bank.v1beta1.MsgSend", &acc);
+        assert!(res.is_ok());
+
+        // underpaid fee is rejected
+        let underpaid = acc.with_fee_setting(FeeSetting::Custom {
+            amount: cosmwasm_std::coin(1, "uaura"),
+            gas_limit: 200_000,
+        });
+        let res: RunnerResult<ExecuteResponse<MsgSendResponse>> =
+            app.execute(msg, "/cosmos.bank.v1beta1.MsgSend", &underpaid);
+        assert!(res.unwrap_err().to_string().contains("insufficient fee"));
+    }
+
+    #[test]
+    fn test_min_gas_prices_apply_to_next_tx() {
+        let app = AuraTestApp::default();
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let underpaid = acc.with_fee_setting(FeeSetting::Custom {
+            amount: cosmwasm_std::coin(1, "uaura"),
+            gas_limit: 200_000,
+        });
+        let msg = MsgSend {
+            from_address: underpaid.address(),
+            to_address: underpaid.address(),
+            amount: vec![Coin {
+                denom: "uaura".to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+
+        // no block between setting the prices and the tx
+        app.set_min_gas_prices("0.025uaura").unwrap();
+        let res: RunnerResult<ExecuteResponse<MsgSendResponse>> =
+            app.execute(msg.clone(), "/cosmos.bank.v1beta1.MsgSend", &underpaid);
+        assert!(res.unwrap_err().to_string().contains("insufficient fee"));
+
+        app.set_min_gas_prices("").unwrap();
+        let res: RunnerResult<ExecuteResponse<MsgSendResponse>> =
+            app.execute(msg, "/cosmos.bank.v1beta1.MsgSend", &underpaid);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_init_account_from_mnemonic_and_private_key() {
+        let app = AuraTestApp::default();
+
+        let mnemonic = "notice oak worry limit wrap speak medal online prefer cluster roof addict wrist behave treat actual wasp year salad speed social layer crew genius";
+        let acc = app
+            .init_account_from_mnemonic(mnemonic, DEFAULT_HD_PATH, &coins(100_000, "uaura"))
+            .unwrap();
+        assert_eq!(get_account_balances(&app, acc.address(), "uaura"), 100_000u128);
+
+        // derivation is deterministic per hd path
+        let other = app
+            .init_account_from_mnemonic(mnemonic, "m/44'/118'/0'/0/1", &[])
+            .unwrap();
+        assert_ne!(other.address(), acc.address());
+
+        // importing the same key funds the same address again
+        let same = app
+            .init_account_from_private_key(&acc.private_key(), &coins(100_000, "uaura"))
+            .unwrap();
+        assert_eq!(same.address(), acc.address());
+        assert_eq!(get_account_balances(&app, acc.address(), "uaura"), 200_000u128);
+
+        assert!(app.init_account_from_mnemonic("not a mnemonic", DEFAULT_HD_PATH, &[]).is_err());
+    }
+
+    #[test]
+    fn test_fund_account() {
+        let app = AuraTestApp::default();
+
+        let addr = app.init_base_account(&coins(1_000, "uaura")).unwrap().address();
+        app.fund_account(&addr, &coins(1_000, "uaura")).unwrap();
+        assert_eq!(get_account_balances(&app, addr, "uaura"), 2_000u128);
+
+        // fee collector is a blocked module account, funding it bypasses the check on purpose
+        let fee_collector = "aura17xpfvakm2amg962yls6f84z3kell8c5lt05zfy";
+        app.fund_account(fee_collector, &coins(1_000, "uaura")).unwrap();
+        assert!(get_account_balances(&app, fee_collector.to_string(), "uaura") >= 1_000u128);
+
+        assert!(app.fund_account("not an address", &coins(1_000, "uaura")).is_err());
+    }
+
+    #[test]
+    fn test_cheatcodes() {
+        let app = AuraTestApp::default();
+
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let to = app.init_base_account(&[]).unwrap();
+
+        app.set_balance(&to.address(), &coins(42, "uaura")).unwrap();
+        assert_eq!(get_account_balances(&app, to.address(), "uaura"), 42u128);
+
+        app.set_balance(&to.address(), &coins(7, "uaura")).unwrap();
+        assert_eq!(get_account_balances(&app, to.address(), "uaura"), 7u128);
+
+        // txs keep working since the signer reads its sequence from chain
+        app.set_account_sequence(&acc.address(), 100).unwrap();
+        let res: RunnerResult<ExecuteResponse<MsgSendResponse>> = app.execute(
+            MsgSend {
+                from_address: acc.address(),
+                to_address: to.address(),
+                amount: vec![Coin {
+                    denom: "uaura".to_string(),
+                    amount: "3".to_string(),
+                }],
+            },
+            "/cosmos.bank.v1beta1.MsgSend",
+            &acc,
+        );
+        assert!(res.is_ok());
+        assert_eq!(get_account_balances(&app, to.address(), "uaura"), 10u128);
+
+        assert!(app.set_contract_admin(&acc.address(), None).is_err());
+    }
+
+    #[test]
+    fn test_set_balance_of_module_account() {
+        let app = AuraTestApp::default();
+        let auth = Auth::new(&app);
+
+        // module accounts are blocked from receiving bank sends
+        let distribution = auth.module_account("distribution").unwrap().base_account.unwrap().address;
+
+        app.set_balance(&distribution, &coins(42, "ufoo")).unwrap();
+        assert_eq!(get_account_balances(&app, distribution.clone(), "ufoo"), 42u128);
+
+        app.set_balance(&distribution, &coins(7, "ufoo")).unwrap();
+        assert_eq!(get_account_balances(&app, distribution, "ufoo"), 7u128);
+    }
+
+    #[test]
+    fn test_contract_cheatcodes() {
+        let app = AuraTestApp::default();
+        let wasm = Wasm::new(&app);
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let other = app.init_base_account(&coins(1, "uaura")).unwrap();
+
+        let code_id = ArtifactRegistry::new(&app).unwrap().store_code("listener", &acc).unwrap();
+        let contract = wasm
+            .instantiate(code_id, &Empty {}, Some(&acc.address()), Some("listener"), &[], &acc)
+            .unwrap()
+            .data
+            .address;
+
+        app.set_contract_state(&contract, b"cheat", b"\"value\"").unwrap();
+        assert_eq!(wasm.query_raw(&contract, b"cheat").unwrap(), b"\"value\"".to_vec());
+
+        // an empty value removes the key
+        app.set_contract_state(&contract, b"cheat", b"").unwrap();
+        assert!(wasm.query_raw(&contract, b"cheat").unwrap().is_empty());
+
+        // no signature from the current admin needed
+        app.set_contract_admin(&contract, Some(&other.address())).unwrap();
+        assert_eq!(wasm.query_contract_info(&contract).unwrap().admin, other.address());
+
+        app.set_contract_admin(&contract, None).unwrap();
+        assert_eq!(wasm.query_contract_info(&contract).unwrap().admin, "");
+    }
+
+    #[test]
+    fn test_gas_snapshot() {
+        let path = std::env::temp_dir().join(format!("aura-gas-snapshot-{}.json", std::process::id()));
+        std::fs::write(&path, "{\"bank send\": 1}").unwrap();
+
+        let app = AuraTestApp::default()
+            .with_gas_recorder(GasRecorder::new(&path).with_tolerance(0.1).fail_on_regression(true));
+        let acc = app.init_base_account(&coins(100_000_000_000, "uaura")).unwrap();
+        let to = app.init_base_account(&[]).unwrap();
+
+        let res: ExecuteResponse<MsgSendResponse> = app
+            .execute(
+                MsgSend {
+                    from_address: acc.address(),
+                    to_address: to.address(),
+                    amount: vec![Coin {
+                        denom: "uaura".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                },
+                "/cosmos.bank.v1beta1.MsgSend",
+                &acc,
+            )
+            .unwrap();
+        app.record_gas("bank send", &res.gas_info);
+
+        // gas grew past the snapshot, which is left untouched
+        let err = app.write_gas_snapshot().unwrap_err();
+        assert!(err.to_string().contains("bank send"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"bank send\": 1}");
+
+        // a fresh snapshot is written without comparing anything
+        std::fs::remove_file(&path).unwrap();
+        let diffs = app.write_gas_snapshot().unwrap();
+        assert_eq!(diffs[0].snapshot, None);
+        assert_eq!(diffs[0].current, res.gas_info.gas_used);
+
+        let diffs = app.write_gas_snapshot().unwrap();
+        assert_eq!(diffs[0].snapshot, Some(res.gas_info.gas_used));
+        std::fs::remove_file(&path).unwrap();
+    }
 
     #[test] 
     fn test_query() {
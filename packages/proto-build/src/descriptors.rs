@@ -0,0 +1,179 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost_types::field_descriptor_proto::Type;
+use prost_types::{DescriptorProto, FileDescriptorSet};
+
+/// What the generated types need to know about the protos, beyond what prost emits.
+#[derive(Default)]
+pub struct Descriptors {
+    packages: BTreeSet<String>,
+    /// full proto name of each message and enum, by package and rust path within it
+    full_names: HashMap<(String, Vec<String>), String>,
+    /// `Query` service method taking each request, by request full name
+    queries: HashMap<String, Query>,
+    /// full names of the `Query` service requests, by package in method order
+    package_queries: HashMap<String, Vec<String>>,
+    /// messages that can't derive `Eq`, having float fields themselves or through other messages
+    non_eq: HashSet<String>,
+}
+
+pub struct Query {
+    /// rpc method name, e.g. `GenerateAccount`
+    pub method: String,
+    /// grpc path of the method, e.g. `/aura.smartaccount.v1beta1.Query/Params`
+    pub path: String,
+    /// rust ident of the response, which must be in the same package as the request
+    pub response_type: String,
+}
+
+/// Float fields and message field types of a message.
+struct MessageFields {
+    has_float: bool,
+    messages: Vec<String>,
+}
+
+impl Descriptors {
+    pub fn new(set: &FileDescriptorSet) -> Self {
+        let mut descriptors = Self::default();
+        let mut fields = HashMap::new();
+
+        for file in &set.file {
+            let package = file.package();
+            descriptors.packages.insert(package.to_owned());
+
+            for message in &file.message_type {
+                descriptors.add_message(package, &[], package, message, &mut fields);
+            }
+            for enumeration in &file.enum_type {
+                descriptors.full_names.insert(
+                    (
+                        package.to_owned(),
+                        vec![enumeration.name().to_upper_camel_case()],
+                    ),
+                    format!("{}.{}", package, enumeration.name()),
+                );
+            }
+
+            for service in file.service.iter().filter(|s| s.name() == "Query") {
+                for method in &service.method {
+                    let request = trim_dot(method.input_type());
+                    let response = trim_dot(method.output_type());
+                    let response = response.rsplit('.').next().unwrap_or(response);
+
+                    descriptors
+                        .package_queries
+                        .entry(package.to_owned())
+                        .or_default()
+                        .push(request.to_owned());
+                    descriptors.queries.insert(
+                        request.to_owned(),
+                        Query {
+                            method: method.name().to_owned(),
+                            path: format!("/{}.{}/{}", package, service.name(), method.name()),
+                            response_type: response.to_upper_camel_case(),
+                        },
+                    );
+                }
+            }
+        }
+
+        // a message is non eq if it has floats, or holds a non eq message
+        loop {
+            let found: Vec<String> = fields
+                .iter()
+                .filter(|(name, f)| {
+                    !descriptors.non_eq.contains(*name)
+                        && (f.has_float || f.messages.iter().any(|m| descriptors.non_eq.contains(m)))
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            if found.is_empty() {
+                break;
+            }
+            descriptors.non_eq.extend(found);
+        }
+
+        descriptors
+    }
+
+    fn add_message(
+        &mut self,
+        package: &str,
+        modules: &[String],
+        scope: &str,
+        message: &DescriptorProto,
+        fields: &mut HashMap<String, MessageFields>,
+    ) {
+        let full_name = format!("{}.{}", scope, message.name());
+
+        let mut path = modules.to_vec();
+        path.push(message.name().to_upper_camel_case());
+        self.full_names
+            .insert((package.to_owned(), path), full_name.clone());
+
+        fields.insert(
+            full_name.clone(),
+            MessageFields {
+                has_float: message
+                    .field
+                    .iter()
+                    .any(|f| matches!(f.r#type(), Type::Float | Type::Double)),
+                messages: message
+                    .field
+                    .iter()
+                    .filter(|f| f.r#type() == Type::Message)
+                    .map(|f| trim_dot(f.type_name()).to_owned())
+                    .collect(),
+            },
+        );
+
+        // prost puts nested types in a module named after the message
+        let mut modules = modules.to_vec();
+        modules.push(message.name().to_snake_case());
+        for nested in &message.nested_type {
+            self.add_message(package, &modules, &full_name, nested, fields);
+        }
+        for enumeration in &message.enum_type {
+            let mut path = modules.clone();
+            path.push(enumeration.name().to_upper_camel_case());
+            self.full_names.insert(
+                (package.to_owned(), path),
+                format!("{}.{}", full_name, enumeration.name()),
+            );
+        }
+    }
+
+    /// Proto packages of all compiled files, including imported ones.
+    pub fn packages(&self) -> impl Iterator<Item = &str> {
+        self.packages.iter().map(String::as_str)
+    }
+
+    /// Full proto name of the type at `path` in the generated module of `package`.
+    pub fn full_name(&self, package: &str, path: &[String]) -> Option<&str> {
+        self.full_names
+            .get(&(package.to_owned(), path.to_vec()))
+            .map(String::as_str)
+    }
+
+    pub fn query(&self, request: &str) -> Option<&Query> {
+        self.queries.get(request)
+    }
+
+    /// Requests of the `Query` service of `package`, with their query.
+    pub fn queries_of<'a>(&'a self, package: &str) -> impl Iterator<Item = (&'a str, &'a Query)> {
+        self.package_queries
+            .get(package)
+            .into_iter()
+            .flatten()
+            .map(|request| (request.as_str(), &self.queries[request]))
+    }
+
+    pub fn is_eq(&self, message: &str) -> bool {
+        !self.non_eq.contains(message)
+    }
+}
+
+fn trim_dot(name: &str) -> &str {
+    name.trim_start_matches('.')
+}
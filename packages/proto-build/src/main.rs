@@ -0,0 +1,180 @@
+//! Generates the `aura-proto` types from the Aura proto files vendored in `proto/`.
+//!
+//! Run `cargo run -p proto-build` after updating the protos. `protoc` must be on `PATH`,
+//! or set with the `PROTOC` env var.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use prost::Message;
+use prost_types::FileDescriptorSet;
+
+use crate::descriptors::Descriptors;
+
+mod descriptors;
+mod strip;
+mod transform;
+
+/// Only packages under this prefix are generated, the module tree starts below it.
+const PACKAGE_PREFIX: &str = "aura";
+
+/// Well known types, mapped to the `aura-proto` shims.
+const EXTERN_PATHS: &[(&str, &str)] = &[
+    (".google.protobuf.Any", "crate::shim::Any"),
+    (".google.protobuf.Timestamp", "crate::shim::Timestamp"),
+    (".google.protobuf.Duration", "crate::shim::Duration"),
+];
+
+const TYPES_HEADER: &str =
+    "//! Generated by `proto-build` from the protos in `packages/proto-build/proto`, do not edit.\n";
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let proto_dir = root.join("proto");
+    let out_dir = root.join("../aura-proto/src/types");
+    let tmp_dir = std::env::temp_dir().join("aura-proto-build");
+
+    recreate_dir(&tmp_dir);
+    let descriptor_path = tmp_dir.join("descriptors.bin");
+    let stripped_dir = tmp_dir.join("proto");
+    let protos = strip_protos(&proto_dir, &stripped_dir);
+
+    let mut config = prost_build::Config::new();
+    config
+        .out_dir(&tmp_dir)
+        .file_descriptor_set_path(&descriptor_path);
+    for (proto_path, rust_path) in EXTERN_PATHS {
+        config.extern_path(*proto_path, *rust_path);
+    }
+    config
+        .compile_protos(&protos, &[&stripped_dir])
+        .expect("failed to compile protos");
+
+    let descriptor_set = fs::read(&descriptor_path).expect("failed to read file descriptors");
+    let descriptor_set = FileDescriptorSet::decode(descriptor_set.as_slice())
+        .expect("failed to decode file descriptors");
+    let descriptors = Descriptors::new(&descriptor_set);
+
+    let mut tree = ModuleTree::default();
+    let prefix = format!("{}.", PACKAGE_PREFIX);
+    for package in descriptors.packages() {
+        let Some(module) = package.strip_prefix(&prefix) else {
+            continue;
+        };
+
+        let src = fs::read_to_string(tmp_dir.join(format!("{}.rs", package)))
+            .unwrap_or_else(|e| panic!("failed to read prost output of {}: {}", package, e));
+        tree.insert(module.split('.'), transform::transform(package, &src, &descriptors));
+    }
+
+    // generated files are found by their header, so hand written ones are kept
+    remove_generated(&out_dir);
+    tree.write(&out_dir);
+}
+
+/// Generated modules, by proto package segment.
+#[derive(Default)]
+struct ModuleTree {
+    code: Option<String>,
+    children: BTreeMap<String, ModuleTree>,
+}
+
+impl ModuleTree {
+    fn insert<'a>(&mut self, mut path: impl Iterator<Item = &'a str>, code: String) {
+        match path.next() {
+            Some(segment) => self
+                .children
+                .entry(segment.to_owned())
+                .or_default()
+                .insert(path, code),
+            None => self.code = Some(code),
+        }
+    }
+
+    /// Writes the module to `dir/mod.rs` if it has children, or to `dir.rs` otherwise.
+    fn write(&self, dir: &Path) {
+        if self.children.is_empty() {
+            let code = self.code.as_deref().unwrap_or_default();
+            write_file(&dir.with_extension("rs"), &format!("{}{}", TYPES_HEADER, code));
+            return;
+        }
+
+        fs::create_dir_all(dir).expect("failed to create module dir");
+        let mut code = TYPES_HEADER.to_owned();
+        for name in self.children.keys() {
+            code.push_str(&format!("pub mod {};\n", name));
+        }
+        if let Some(own) = &self.code {
+            code.push('\n');
+            code.push_str(own);
+        }
+        write_file(&dir.join("mod.rs"), &code);
+
+        for (name, child) in &self.children {
+            child.write(&dir.join(name));
+        }
+    }
+}
+
+/// Writes the stripped copies of the vendored protos to `out_dir`, returning their paths.
+fn strip_protos(proto_dir: &Path, out_dir: &Path) -> Vec<PathBuf> {
+    collect_protos(&proto_dir.join(PACKAGE_PREFIX))
+        .into_iter()
+        .map(|proto| {
+            let path = out_dir.join(proto.strip_prefix(proto_dir).expect("proto outside proto dir"));
+            let src = fs::read_to_string(&proto)
+                .unwrap_or_else(|e| panic!("failed to read {}: {}", proto.display(), e));
+
+            fs::create_dir_all(path.parent().expect("proto without parent dir"))
+                .expect("failed to create proto dir");
+            write_file(&path, &strip::strip(&src));
+            path
+        })
+        .collect()
+}
+
+fn collect_protos(dir: &Path) -> Vec<PathBuf> {
+    let mut protos = vec![];
+    for entry in fs::read_dir(dir).expect("failed to read proto dir") {
+        let path = entry.expect("failed to read proto dir entry").path();
+        if path.is_dir() {
+            protos.extend(collect_protos(&path));
+        } else if path.extension().is_some_and(|ext| ext == "proto") {
+            protos.push(path);
+        }
+    }
+    protos.sort();
+
+    protos
+}
+
+fn recreate_dir(dir: &Path) {
+    if dir.exists() {
+        fs::remove_dir_all(dir).expect("failed to remove dir");
+    }
+    fs::create_dir_all(dir).expect("failed to create dir");
+}
+
+/// Removes the files starting with the generated header, and the dirs left empty.
+fn remove_generated(dir: &Path) {
+    if !dir.exists() {
+        return;
+    }
+
+    for entry in fs::read_dir(dir).expect("failed to read types dir") {
+        let path = entry.expect("failed to read types dir entry").path();
+        if path.is_dir() {
+            remove_generated(&path);
+            if fs::read_dir(&path).expect("failed to read types dir").next().is_none() {
+                fs::remove_dir(&path).expect("failed to remove dir");
+            }
+        } else if fs::read_to_string(&path).is_ok_and(|code| code.starts_with(TYPES_HEADER)) {
+            fs::remove_file(&path).expect("failed to remove generated file");
+        }
+    }
+}
+
+fn write_file(path: &Path, code: &str) {
+    fs::write(path, code).unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+}
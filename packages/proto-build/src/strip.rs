@@ -0,0 +1,270 @@
+//! Strips the proto extensions the generated types don't use, so the upstream protos can be
+//! vendored unchanged without vendoring the extensions they import.
+
+/// Import path and option prefixes of the stripped extensions.
+const STRIPPED: &[(&str, &str)] = &[
+    ("gogoproto/", "(gogoproto."),
+    ("cosmos_proto/", "(cosmos_proto."),
+    ("amino/", "(amino."),
+    ("cosmos/msg/", "(cosmos.msg."),
+    ("google/api/", "(google.api."),
+];
+
+/// Removes the imports of the stripped extensions and all their options from a proto file.
+pub fn strip(src: &str) -> String {
+    let src: String = src
+        .lines()
+        .filter(|line| !is_stripped_import(line))
+        .map(|line| format!("{}\n", line))
+        .collect();
+
+    let bytes = src.as_bytes();
+    let mut out = String::with_capacity(src.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'"' | b'\'' => skip_string(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') => skip_until(bytes, i, "\n"),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => skip_until(bytes, i, "*/"),
+            b'[' => {
+                let end = skip_group(bytes, i, b'[', b']');
+                out.push_str(&strip_field_options(&src[i + 1..end - 1]));
+                i = end;
+                continue;
+            }
+            b'o' if is_stripped_option(&src, i) => {
+                i = skip_statement(bytes, i);
+                continue;
+            }
+            _ => i + 1,
+        };
+        out.push_str(&src[i..end]);
+        i = end;
+    }
+
+    out
+}
+
+fn is_stripped_import(line: &str) -> bool {
+    let Some(path) = line.trim_start().strip_prefix("import") else {
+        return false;
+    };
+    let path = path.trim_start().trim_start_matches("public").trim_start();
+
+    STRIPPED
+        .iter()
+        .any(|(import, _)| path.starts_with(&format!("\"{}", import)))
+}
+
+/// Whether an `option (<extension>...) = ...;` statement of a stripped extension starts at `i`.
+fn is_stripped_option(src: &str, i: usize) -> bool {
+    let starts_word = i == 0 || !is_ident_byte(src.as_bytes()[i - 1]);
+    let Some(rest) = src[i..].strip_prefix("option") else {
+        return false;
+    };
+    if !starts_word || rest.bytes().next().is_some_and(is_ident_byte) {
+        return false;
+    }
+
+    is_stripped_name(rest.trim_start())
+}
+
+fn is_stripped_name(option: &str) -> bool {
+    STRIPPED
+        .iter()
+        .any(|(_, prefix)| option.starts_with(prefix))
+}
+
+/// Keeps the field options of other extensions, drops the brackets if none are left.
+fn strip_field_options(options: &str) -> String {
+    let kept: Vec<&str> = split_top_level(options)
+        .into_iter()
+        .map(str::trim)
+        .filter(|option| !is_stripped_name(option))
+        .collect();
+
+    if kept.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", kept.join(", "))
+    }
+}
+
+/// Splits on the commas outside of strings and message literals.
+fn split_top_level(src: &str) -> Vec<&str> {
+    let bytes = src.as_bytes();
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        i = match bytes[i] {
+            b'"' | b'\'' => skip_string(bytes, i),
+            b'{' => skip_group(bytes, i, b'{', b'}'),
+            b',' => {
+                parts.push(&src[start..i]);
+                start = i + 1;
+                i + 1
+            }
+            _ => i + 1,
+        };
+    }
+    parts.push(&src[start..]);
+
+    parts
+}
+
+/// End of the statement starting at `i`, after its `;`.
+fn skip_statement(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() {
+        i = match bytes[i] {
+            b'"' | b'\'' => skip_string(bytes, i),
+            b'{' => skip_group(bytes, i, b'{', b'}'),
+            b';' => return i + 1,
+            _ => i + 1,
+        };
+    }
+
+    i
+}
+
+/// End of the group opened at `i`, after its closing delimiter.
+fn skip_group(bytes: &[u8], mut i: usize, open: u8, close: u8) -> usize {
+    let mut depth = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        i = match b {
+            b'"' | b'\'' => skip_string(bytes, i),
+            _ => i + 1,
+        };
+        if b == open {
+            depth += 1;
+        } else if b == close {
+            depth -= 1;
+            if depth == 0 {
+                return i;
+            }
+        }
+    }
+
+    i
+}
+
+/// End of the string literal opened at `i`, after its closing quote.
+fn skip_string(bytes: &[u8], i: usize) -> usize {
+    let quote = bytes[i];
+    let mut j = i + 1;
+    while j < bytes.len() {
+        match bytes[j] {
+            b'\\' => j += 2,
+            b if b == quote => return j + 1,
+            _ => j += 1,
+        }
+    }
+
+    j
+}
+
+/// End of the comment starting at `i`, after `terminator`.
+fn skip_until(bytes: &[u8], i: usize, terminator: &str) -> usize {
+    bytes[i + 2..]
+        .windows(terminator.len())
+        .position(|w| w == terminator.as_bytes())
+        .map_or(bytes.len(), |pos| i + 2 + pos + terminator.len())
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip;
+
+    #[test]
+    fn test_strips_extension_imports() {
+        let src = r#"syntax = "proto3";
+import "gogoproto/gogo.proto";
+import public "cosmos_proto/cosmos.proto";
+import "google/api/annotations.proto";
+import "google/protobuf/any.proto";
+import "aura/smartaccount/v1beta1/params.proto";
+"#;
+
+        assert_eq!(
+            strip(src),
+            r#"syntax = "proto3";
+import "google/protobuf/any.proto";
+import "aura/smartaccount/v1beta1/params.proto";
+"#
+        );
+    }
+
+    #[test]
+    fn test_strips_extension_options() {
+        let src = r#"option go_package = "github.com/aura-nw/aura/x/smartaccount/types";
+option (gogoproto.equal_all) = true;
+service Msg {
+  option (cosmos.msg.v1.service) = true;
+  rpc Params(QueryParamsRequest) returns (QueryParamsResponse) {
+    option (google.api.http) = { get: "/aura/smartaccount/v1beta1/params"; additional_bindings { get: "/params" } };
+  }
+}
+message MsgRecover {
+  option (cosmos.msg.v1.signer) = "creator";
+  option (amino.name) = "smartaccount/MsgRecover";
+  option deprecated = true;
+}
+"#;
+
+        assert_eq!(
+            strip(src),
+            r#"option go_package = "github.com/aura-nw/aura/x/smartaccount/types";
+
+service Msg {
+  
+  rpc Params(QueryParamsRequest) returns (QueryParamsResponse) {
+    
+  }
+}
+message MsgRecover {
+  
+  
+  option deprecated = true;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_keeps_other_field_options() {
+        let src = r#"message Params {
+  repeated CodeID whitelist_code_id = 1 [(gogoproto.nullable) = false, (gogoproto.moretags) = "yaml:\"a, b\""];
+  string address = 2 [(cosmos_proto.scalar) = "cosmos.AddressString", deprecated = true];
+  uint64 max_gas_execute = 3 [deprecated = true, (amino.dont_omitempty) = true, json_name = "max"];
+}
+"#;
+
+        assert_eq!(
+            strip(src),
+            r#"message Params {
+  repeated CodeID whitelist_code_id = 1 ;
+  string address = 2 [deprecated = true];
+  uint64 max_gas_execute = 3 [deprecated = true, json_name = "max"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_leaves_comments_and_strings_alone() {
+        let src = r#"// option (gogoproto.equal) = true; [(gogoproto.nullable) = false]
+/* option (amino.name) = "x"; */
+message CodeID {
+  // a doc comment, with [brackets]
+  uint64 code_id = 1 [json_name = "option (gogoproto.x) = y;"];
+}
+"#;
+
+        assert_eq!(strip(src), src);
+    }
+}
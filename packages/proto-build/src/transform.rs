@@ -0,0 +1,549 @@
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Attribute, Ident, Item, ItemEnum, ItemStruct, Meta, Token};
+
+use crate::descriptors::Descriptors;
+
+/// Names types had in `aura-proto` before it was generated, by proto full name.
+const FORMER_NAMES: &[(&str, &str)] = &[
+    (
+        "aura.smartaccount.v1beta1.MsgActivateAccount",
+        "MsgActivateAccountRequest",
+    ),
+    ("aura.smartaccount.v1beta1.MsgRecover", "MsgRecoverRequest"),
+];
+
+/// Scalars serialized as strings, like the chain does in json.
+const STRING_ENCODED: &[&str] = &["int64", "uint64", "sint64", "fixed64", "sfixed64"];
+
+/// Turns the prost output of `package` into `aura-proto` types.
+pub fn transform(package: &str, src: &str, descriptors: &Descriptors) -> String {
+    let file = syn::parse_file(src)
+        .unwrap_or_else(|e| panic!("failed to parse prost output of {}: {}", package, e));
+
+    let mut items = transform_items(package, &[], file.items, descriptors);
+    let querier = querier(package, &items, descriptors);
+    items.extend(querier);
+
+    let file = syn::File { items, ..file };
+
+    prettyplease::unparse(&file)
+}
+
+fn transform_items(
+    package: &str,
+    modules: &[String],
+    items: Vec<Item>,
+    descriptors: &Descriptors,
+) -> Vec<Item> {
+    let mut items: Vec<Item> = items
+        .into_iter()
+        .flat_map(|item| match item {
+            Item::Struct(s) if derives(&s.attrs, "Message") => {
+                let aliases = aliases(package, modules, &s.ident, descriptors);
+                let message = transform_message(package, modules, s, descriptors);
+                [vec![Item::Struct(message)], aliases].concat()
+            }
+            Item::Enum(e) if derives(&e.attrs, "Enumeration") => {
+                let aliases = aliases(package, modules, &e.ident, descriptors);
+                [vec![Item::Enum(transform_enumeration(e))], aliases].concat()
+            }
+            Item::Enum(e) if derives(&e.attrs, "Oneof") => {
+                vec![Item::Enum(transform_oneof(package, modules, e, descriptors))]
+            }
+            Item::Mod(mut m) => {
+                if let Some((brace, nested)) = m.content.take() {
+                    let mut modules = modules.to_vec();
+                    modules.push(m.ident.to_string());
+                    m.content = Some((
+                        brace,
+                        transform_items(package, &modules, nested, descriptors),
+                    ));
+                }
+                vec![Item::Mod(m)]
+            }
+            item => vec![item],
+        })
+        .collect();
+
+    let has_messages = items
+        .iter()
+        .any(|item| matches!(item, Item::Struct(s) if derives(&s.attrs, "Message")));
+    if has_messages {
+        items.insert(0, parse_quote!(use aura_proto_derive::CosmwasmExt;));
+    }
+
+    items
+}
+
+/// `<Module>Querier`, wrapping `QuerierWrapper` with a method per `Query` service rpc.
+fn querier(package: &str, items: &[Item], descriptors: &Descriptors) -> Vec<Item> {
+    let methods: Vec<TokenStream> = descriptors
+        .queries_of(package)
+        .map(|(request, query)| {
+            let request = request.rsplit('.').next().unwrap_or(request);
+            let request = format_ident!("{}", request.to_upper_camel_case());
+            let fields = items
+                .iter()
+                .find_map(|item| match item {
+                    Item::Struct(s) if s.ident == request => Some(&s.fields),
+                    _ => None,
+                })
+                .unwrap_or_else(|| panic!("no request {} in {}", request, package));
+
+            let names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+            let types = fields.iter().map(|f| &f.ty);
+            let method = format_ident!("{}", query.method.to_snake_case());
+            let response = format_ident!("{}", query.response_type);
+
+            quote! {
+                pub fn #method(
+                    &self,
+                    #(#names: #types),*
+                ) -> std::result::Result<#response, cosmwasm_std::StdError> {
+                    #request { #(#names),* }.query(self.querier)
+                }
+            }
+        })
+        .collect();
+
+    if methods.is_empty() {
+        return vec![];
+    }
+
+    // named after the module, e.g. `aura.smartaccount.v1beta1` gives `SmartaccountQuerier`
+    let module = package.rsplit('.').nth(1).unwrap_or(package);
+    let querier = format_ident!("{}Querier", module.to_upper_camel_case());
+    let doc = format!(" Typed queries to the `{}` module, sent as stargate queries.", package);
+
+    let file: syn::File = parse_quote! {
+        #[doc = #doc]
+        pub struct #querier<'a, Q: cosmwasm_std::CustomQuery> {
+            querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
+        }
+
+        impl<'a, Q: cosmwasm_std::CustomQuery> #querier<'a, Q> {
+            pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
+                Self { querier }
+            }
+
+            #(#methods)*
+        }
+    };
+
+    file.items
+}
+
+/// Deprecated aliases of a type, for its proto name if prost renamed it, e.g. `CodeID` for
+/// `CodeId`, and for the names it had in `aura-proto` before being generated.
+fn aliases(
+    package: &str,
+    modules: &[String],
+    ident: &Ident,
+    descriptors: &Descriptors,
+) -> Vec<Item> {
+    let mut path = modules.to_vec();
+    path.push(ident.to_string());
+    let Some(full_name) = descriptors.full_name(package, &path) else {
+        return vec![];
+    };
+    let proto_name = full_name.rsplit('.').next().unwrap_or(full_name);
+
+    let previous = FORMER_NAMES
+        .iter()
+        .filter(|(name, _)| *name == full_name)
+        .map(|(_, previous)| *previous);
+    let note = format!("renamed to `{}`", ident);
+
+    (ident != proto_name)
+        .then_some(proto_name)
+        .into_iter()
+        .chain(previous)
+        .map(|alias| {
+            let alias = format_ident!("{}", alias);
+            parse_quote! {
+                #[deprecated(note = #note)]
+                pub type #alias = #ident;
+            }
+        })
+        .collect()
+}
+
+fn transform_message(
+    package: &str,
+    modules: &[String],
+    mut message: ItemStruct,
+    descriptors: &Descriptors,
+) -> ItemStruct {
+    let mut path = modules.to_vec();
+    path.push(message.ident.to_string());
+    let full_name = descriptors
+        .full_name(package, &path)
+        .unwrap_or_else(|| panic!("no descriptor for {}::{}", package, path.join("::")));
+
+    let eq = descriptors.is_eq(full_name).then(|| quote!(Eq,));
+    let type_url = format!("/{}", full_name);
+
+    // keeps the doc comments, the derives are replaced
+    message
+        .attrs
+        .retain(|attr| !attr.path().is_ident("derive") && !attr.path().is_ident("allow"));
+    message.attrs.extend([
+        parse_quote!(#[allow(clippy::derive_partial_eq_without_eq)]),
+        parse_quote! {
+            #[derive(
+                Clone,
+                PartialEq,
+                #eq
+                ::prost::Message,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+                ::schemars::JsonSchema,
+                CosmwasmExt,
+            )]
+        },
+        parse_quote!(#[proto_message(type_url = #type_url)]),
+    ]);
+
+    if let Some(query) = descriptors.query(full_name) {
+        let path = &query.path;
+        let response_type = format_ident!("{}", query.response_type);
+        message.attrs.push(parse_quote! {
+            #[proto_query(path = #path, response_type = #response_type)]
+        });
+    }
+
+    for field in message.fields.iter_mut() {
+        if is_string_encoded(&field.attrs) {
+            field.attrs.push(parse_quote! {
+                #[serde(
+                    serialize_with = "crate::serde::as_str::serialize",
+                    deserialize_with = "crate::serde::as_str::deserialize"
+                )]
+            });
+        }
+    }
+
+    message
+}
+
+fn transform_enumeration(mut enumeration: ItemEnum) -> ItemEnum {
+    let mut derives = derive_paths(&enumeration.attrs);
+    derives.extend([
+        parse_quote!(::serde::Serialize),
+        parse_quote!(::serde::Deserialize),
+        parse_quote!(::schemars::JsonSchema),
+    ]);
+
+    enumeration
+        .attrs
+        .retain(|attr| !attr.path().is_ident("derive"));
+    enumeration
+        .attrs
+        .push(parse_quote!(#[derive(#(#derives),*)]));
+
+    enumeration
+}
+
+/// Oneofs live in the module of their message, and are `Eq` if the message is.
+fn transform_oneof(
+    package: &str,
+    modules: &[String],
+    mut oneof: ItemEnum,
+    descriptors: &Descriptors,
+) -> ItemEnum {
+    let message = modules.last().map(|module| {
+        let mut path = modules[..modules.len() - 1].to_vec();
+        path.push(module.to_upper_camel_case());
+        path
+    });
+    let eq = message
+        .and_then(|path| descriptors.full_name(package, &path))
+        .is_some_and(|full_name| descriptors.is_eq(full_name))
+        .then(|| quote!(Eq,));
+
+    oneof
+        .attrs
+        .retain(|attr| !attr.path().is_ident("derive") && !attr.path().is_ident("allow"));
+    oneof.attrs.extend([
+        parse_quote!(#[allow(clippy::derive_partial_eq_without_eq)]),
+        parse_quote! {
+            #[derive(
+                Clone,
+                PartialEq,
+                #eq
+                ::prost::Oneof,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+                ::schemars::JsonSchema,
+            )]
+        },
+    ]);
+
+    oneof
+}
+
+fn derive_paths(attrs: &[Attribute]) -> Vec<syn::Path> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .flat_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .expect("invalid derive")
+        })
+        .collect()
+}
+
+fn derives(attrs: &[Attribute], name: &str) -> bool {
+    derive_paths(attrs)
+        .iter()
+        .any(|path| path.segments.last().is_some_and(|s| s.ident == name))
+}
+
+/// Whether a `#[prost(...)]` field is a single, non optional, 64 bit integer.
+fn is_string_encoded(attrs: &[Attribute]) -> bool {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("prost")) else {
+        return false;
+    };
+    let metas: Vec<Meta> = attr
+        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .expect("invalid prost attribute")
+        .into_iter()
+        .collect();
+
+    let is_64_bit = matches!(
+        metas.first(),
+        Some(Meta::Path(kind)) if STRING_ENCODED.iter().any(|s| kind.is_ident(s))
+    );
+    let is_single = !metas.iter().any(|meta| {
+        matches!(meta, Meta::Path(label) if label.is_ident("repeated") || label.is_ident("optional"))
+    });
+
+    is_64_bit && is_single
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+        MethodDescriptorProto, ServiceDescriptorProto,
+    };
+    use quote::ToTokens;
+    use syn::{Item, ItemStruct};
+
+    use super::*;
+
+    const PACKAGE: &str = "aura.smartaccount.v1beta1";
+
+    const PROST_OUTPUT: &str = r#"
+        /// CodeID is a wasm code allowed to back smart accounts.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct CodeId {
+            #[prost(uint64, tag = "1")]
+            pub code_id: u64,
+            #[prost(uint64, repeated, tag = "2")]
+            pub code_ids: ::prost::alloc::vec::Vec<u64>,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct MsgRecover {
+            #[prost(string, tag = "1")]
+            pub address: ::prost::alloc::string::String,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Price {
+            #[prost(double, tag = "1")]
+            pub amount: f64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct QueryPriceRequest {
+            #[prost(uint64, tag = "1")]
+            pub code_id: u64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct QueryPriceResponse {
+            #[prost(message, optional, tag = "1")]
+            pub price: ::core::option::Option<Price>,
+        }
+    "#;
+
+    fn field(name: &str, r#type: Type, label: Label, type_name: &str) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_owned()),
+            r#type: Some(r#type as i32),
+            label: Some(label as i32),
+            type_name: (!type_name.is_empty()).then(|| type_name.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn message(name: &str, field: Vec<FieldDescriptorProto>) -> DescriptorProto {
+        DescriptorProto {
+            name: Some(name.to_owned()),
+            field,
+            ..Default::default()
+        }
+    }
+
+    fn descriptors() -> Descriptors {
+        let query = MethodDescriptorProto {
+            name: Some("Price".to_owned()),
+            input_type: Some(format!(".{}.QueryPriceRequest", PACKAGE)),
+            output_type: Some(format!(".{}.QueryPriceResponse", PACKAGE)),
+            ..Default::default()
+        };
+
+        Descriptors::new(&FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                package: Some(PACKAGE.to_owned()),
+                message_type: vec![
+                    message(
+                        "CodeID",
+                        vec![
+                            field("code_id", Type::Uint64, Label::Optional, ""),
+                            field("code_ids", Type::Uint64, Label::Repeated, ""),
+                        ],
+                    ),
+                    message(
+                        "MsgRecover",
+                        vec![field("address", Type::String, Label::Optional, "")],
+                    ),
+                    message(
+                        "Price",
+                        vec![field("amount", Type::Double, Label::Optional, "")],
+                    ),
+                    message(
+                        "QueryPriceRequest",
+                        vec![field("code_id", Type::Uint64, Label::Optional, "")],
+                    ),
+                    message(
+                        "QueryPriceResponse",
+                        vec![field(
+                            "price",
+                            Type::Message,
+                            Label::Optional,
+                            &format!(".{}.Price", PACKAGE),
+                        )],
+                    ),
+                ],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Query".to_owned()),
+                    method: vec![query],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        })
+    }
+
+    fn transformed() -> syn::File {
+        syn::parse_file(&transform(PACKAGE, PROST_OUTPUT, &descriptors())).unwrap()
+    }
+
+    fn find_struct<'a>(file: &'a syn::File, name: &str) -> &'a ItemStruct {
+        file.items
+            .iter()
+            .find_map(|item| match item {
+                Item::Struct(s) if s.ident == name => Some(s),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("no struct {}", name))
+    }
+
+    fn attr<'a>(s: &'a ItemStruct, name: &str) -> Option<&'a Attribute> {
+        s.attrs.iter().find(|attr| attr.path().is_ident(name))
+    }
+
+    fn alias_target(file: &syn::File, alias: &str) -> Option<String> {
+        file.items.iter().find_map(|item| match item {
+            Item::Type(t) if t.ident == alias => {
+                assert!(t.attrs.iter().any(|attr| attr.path().is_ident("deprecated")));
+                Some(t.ty.to_token_stream().to_string())
+            }
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_message_derives_and_type_url() {
+        let file = transformed();
+        let code_id = find_struct(&file, "CodeId");
+
+        assert!(attr(code_id, "doc").is_some());
+        assert!(derives(&code_id.attrs, "Eq"));
+        assert!(derives(&code_id.attrs, "CosmwasmExt"));
+        assert!(derives(&code_id.attrs, "JsonSchema"));
+        assert_eq!(
+            attr(code_id, "proto_message").unwrap().to_token_stream().to_string(),
+            r#"# [proto_message (type_url = "/aura.smartaccount.v1beta1.CodeID")]"#
+        );
+        assert!(attr(code_id, "proto_query").is_none());
+
+        // floats, and messages holding them, can't be Eq
+        assert!(!derives(&find_struct(&file, "Price").attrs, "Eq"));
+        assert!(!derives(&find_struct(&file, "QueryPriceResponse").attrs, "Eq"));
+    }
+
+    #[test]
+    fn test_single_64_bit_integers_are_strings() {
+        let file = transformed();
+        let fields: Vec<_> = find_struct(&file, "CodeId").fields.iter().collect();
+
+        assert!(fields[0].attrs.iter().any(|attr| attr.path().is_ident("serde")));
+        assert!(!fields[1].attrs.iter().any(|attr| attr.path().is_ident("serde")));
+    }
+
+    #[test]
+    fn test_renamed_types_keep_deprecated_aliases() {
+        let file = transformed();
+
+        assert_eq!(alias_target(&file, "CodeID").as_deref(), Some("CodeId"));
+        assert_eq!(alias_target(&file, "MsgRecoverRequest").as_deref(), Some("MsgRecover"));
+        assert_eq!(alias_target(&file, "MsgRecover"), None);
+        assert_eq!(alias_target(&file, "Price"), None);
+    }
+
+    #[test]
+    fn test_query_requests_and_querier() {
+        let file = transformed();
+
+        assert_eq!(
+            attr(find_struct(&file, "QueryPriceRequest"), "proto_query")
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            r#"# [proto_query (path = "/aura.smartaccount.v1beta1.Query/Price" , response_type = QueryPriceResponse)]"#
+        );
+
+        find_struct(&file, "SmartaccountQuerier");
+        let querier = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Impl(i) => Some(i),
+                _ => None,
+            })
+            .unwrap();
+        assert!(querier
+            .self_ty
+            .to_token_stream()
+            .to_string()
+            .starts_with("SmartaccountQuerier"));
+        let methods: Vec<String> = querier
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(f) => Some(f.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(methods, ["new", "price"]);
+    }
+}
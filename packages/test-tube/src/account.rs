@@ -21,6 +21,7 @@ pub struct SigningAccount {
     signing_key: SigningKey,
     private_key: Vec<u8>,
     fee_setting: FeeSetting,
+    fee_granter: Option<String>,
 }
 
 impl SigningAccount {
@@ -30,6 +31,7 @@ impl SigningAccount {
             signing_key,
             private_key,
             fee_setting,
+            fee_granter: None,
         }
     }
 
@@ -43,6 +45,19 @@ impl SigningAccount {
             signing_key: self.signing_key,
             private_key: self.private_key,
             fee_setting,
+            fee_granter: self.fee_granter,
+        }
+    }
+
+    pub fn fee_granter(&self) -> Option<&str> {
+        self.fee_granter.as_deref()
+    }
+
+    /// Have txs signed by this account pay fees from the allowance `granter` gave it.
+    pub fn with_fee_granter(self, granter: &str) -> Self {
+        Self {
+            fee_granter: Some(granter.to_string()),
+            ..self
         }
     }
 }
@@ -101,6 +116,12 @@ pub enum FeeSetting {
         gas_price: f64,
         gas_adjustment: f64,
     },
+    /// Same as `Auto`, but the estimated gas limit never exceeds `max_gas_limit`.
+    AutoCapped {
+        gas_price: f64,
+        gas_adjustment: f64,
+        max_gas_limit: u64,
+    },
     Custom {
         amount: Coin,
         gas_limit: u64,
@@ -256,3 +256,67 @@ extern "C" {
         typeUrl: GoString,
     ) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn SetMinGasPrices(envId: GoUint64, minGasPrices: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn InitAccountFromMnemonic(
+        envId: GoUint64,
+        mnemonic: GoString,
+        hdPath: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn InitAccountFromPrivKey(
+        envId: GoUint64,
+        base64PrivKey: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn FundAccount(
+        envId: GoUint64,
+        bech32Address: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetBalance(
+        envId: GoUint64,
+        bech32Address: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetContractState(
+        envId: GoUint64,
+        bech32Contract: GoString,
+        base64Key: GoString,
+        base64Value: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetAccountSequence(
+        envId: GoUint64,
+        bech32Address: GoString,
+        sequence: GoUint64,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetContractAdmin(
+        envId: GoUint64,
+        bech32Contract: GoString,
+        bech32Admin: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn WasmSudo(
+        envId: GoUint64,
+        bech32Contract: GoString,
+        base64Msg: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SudoTraces(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
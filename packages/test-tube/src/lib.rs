@@ -12,5 +12,7 @@ pub use account::{Account, NonSigningAccount, SigningAccount};
 pub use module::*;
 pub use runner::app::BaseApp;
 pub use runner::error::{DecodeError, EncodeError, RunnerError};
+pub use runner::gas::{GasDiff, GasRecorder};
+pub use runner::hooks::HookInvocation;
 pub use runner::result::{ExecuteResponse, RunnerExecuteResult, RunnerResult};
 pub use runner::Runner;
@@ -11,11 +11,15 @@ use cosmwasm_std::Coin;
 
 use crate::account::{Account, FeeSetting, SigningAccount, ADDRESS_PREFIX};
 use crate::bindings::{
-    AccountNumber, AccountSequence, BeginBlock, EndBlock, Execute, GetParamSet, InitAccount,
-    InitTestEnv, Query, SetParamSet, Simulate, SkipTime,
+    AccountNumber, AccountSequence, BeginBlock, EndBlock, Execute, FundAccount, GetParamSet,
+    InitAccount, InitAccountFromMnemonic, InitAccountFromPrivKey, InitTestEnv, Query,
+    SetAccountSequence, SetBalance, SetContractAdmin, SetContractState, SetMinGasPrices,
+    SetParamSet, Simulate, SkipTime, SudoTraces, WasmSudo,
 };
 use crate::redefine_as_go_string;
 use crate::runner::error::{DecodeError, EncodeError, RunnerError};
+use crate::runner::gas::{GasDiff, GasRecorder};
+use crate::runner::hooks::HookInvocation;
 use crate::runner::result::RawResult;
 use crate::runner::result::{RunnerExecuteResult, RunnerResult};
 use crate::runner::Runner;
@@ -25,31 +29,79 @@ pub struct BaseApp {
     id: u64,
     fee_denom: String,
     chain_id: String,
-    default_gas_adjustment: f64,
-    default_gas_limit: u64,
+    default_fee_setting: FeeSetting,
+    gas_recorder: Option<GasRecorder>,
 }
 
 impl BaseApp {
-    pub fn new(fee_denom: &str, chain_id: &str, default_gas_adjustment: f64, default_gas_limit: u64) -> Self {
+    /// Create a new app whose accounts pay fees with `default_gas_price`,
+    /// estimating gas with `default_gas_adjustment` and capping the gas limit
+    /// at `default_gas_limit`.
+    pub fn new(
+        fee_denom: &str,
+        chain_id: &str,
+        default_gas_price: f64,
+        default_gas_adjustment: f64,
+        default_gas_limit: u64,
+    ) -> Self {
         let id = unsafe { InitTestEnv() };
         BaseApp {
             id,
             fee_denom: fee_denom.to_string(),
             chain_id: chain_id.to_string(),
-            default_gas_adjustment,
-            default_gas_limit,
+            default_fee_setting: FeeSetting::AutoCapped {
+                gas_price: default_gas_price,
+                gas_adjustment: default_gas_adjustment,
+                max_gas_limit: default_gas_limit,
+            },
+            gas_recorder: None,
+        }
+    }
+
+    /// Replace the fee setting given to accounts created by this app.
+    pub fn with_default_fee_setting(self, fee_setting: FeeSetting) -> Self {
+        Self {
+            default_fee_setting: fee_setting,
+            ..self
+        }
+    }
+
+    pub fn default_fee_setting(&self) -> &FeeSetting {
+        &self.default_fee_setting
+    }
+
+    /// Opt in to recording gas with `record_gas` and checking it with `write_gas_snapshot`.
+    pub fn with_gas_recorder(self, gas_recorder: GasRecorder) -> Self {
+        Self {
+            gas_recorder: Some(gas_recorder),
+            ..self
+        }
+    }
+
+    pub fn gas_recorder(&self) -> Option<&GasRecorder> {
+        self.gas_recorder.as_ref()
+    }
+
+    /// Record gas used under `label`. Does nothing without a gas recorder.
+    pub fn record_gas(&self, label: &str, gas_info: &GasInfo) {
+        if let Some(recorder) = &self.gas_recorder {
+            recorder.record(label, gas_info);
+        }
+    }
+
+    /// Compare recorded gas against the snapshot file and update it.
+    /// Returns no diffs without a gas recorder.
+    pub fn write_gas_snapshot(&self) -> RunnerResult<Vec<GasDiff>> {
+        match &self.gas_recorder {
+            Some(recorder) => recorder.write_snapshot(),
+            None => Ok(vec![]),
         }
     }
 
     /// Initialize account with initial balance of any coins.
     /// This function mints new coins and send to newly created account
     pub fn init_base_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
-        let mut coins = coins.to_vec();
-
-        // invalid coins if denom are unsorted
-        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
-
-        let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
+        let coins_json = coins_to_json(coins)?;
         redefine_as_go_string!(coins_json);
 
         let base64_priv = unsafe {
@@ -63,6 +115,79 @@ impl BaseApp {
         .to_string();
 
         let secp256k1_priv = base64::decode(base64_priv).map_err(DecodeError::Base64DecodeError)?;
+
+        self.signing_account_from_private_key(secp256k1_priv)
+    }
+
+    /// Initialize account from a BIP-39 mnemonic, derived at `hd_path`
+    /// (e.g. `"m/44'/118'/0'/0/0"`), with initial balance of any coins.
+    /// This function mints new coins and send to the derived account
+    pub fn init_account_from_mnemonic(
+        &self,
+        mnemonic: &str,
+        hd_path: &str,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        let coins_json = coins_to_json(coins)?;
+        redefine_as_go_string!(mnemonic, hd_path, coins_json);
+
+        let secp256k1_priv = unsafe {
+            BeginBlock(self.id);
+            let res = InitAccountFromMnemonic(self.id, mnemonic, hd_path, coins_json);
+            EndBlock(self.id);
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        self.signing_account_from_private_key(secp256k1_priv)
+    }
+
+    /// Initialize account from raw secp256k1 private key bytes
+    /// with initial balance of any coins.
+    /// This function mints new coins and send to the key's account
+    pub fn init_account_from_private_key(
+        &self,
+        private_key: &[u8],
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        // fail early on invalid keys before touching the chain
+        let account = self.signing_account_from_private_key(private_key.to_vec())?;
+
+        let coins_json = coins_to_json(coins)?;
+        let base64_priv = base64::encode(private_key);
+        redefine_as_go_string!(base64_priv, coins_json);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = InitAccountFromPrivKey(self.id, base64_priv, coins_json);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(account)
+    }
+
+    /// Mint coins and send them to any bech32 address.
+    /// Coins are sent without the bank blocked address check,
+    /// so contracts and module accounts can be funded too.
+    pub fn fund_account(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        let coins_json = coins_to_json(coins)?;
+        redefine_as_go_string!(address, coins_json);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = FundAccount(self.id, address, coins_json);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    fn signing_account_from_private_key(&self, secp256k1_priv: Vec<u8>) -> RunnerResult<SigningAccount> {
         let signging_key = SigningKey::from_bytes(&secp256k1_priv).map_err(|e| {
             let msg = e.to_string();
             DecodeError::SigningKeyDecodeError { msg }
@@ -74,10 +199,7 @@ impl BaseApp {
                 .expect("ADDRESS_PREFIX is constant and must valid").to_string(),
             signging_key,
             secp256k1_priv,
-            FeeSetting::Auto {
-                gas_price: 0.025f64,
-                gas_adjustment: self.default_gas_adjustment,
-            },
+            self.default_fee_setting.clone(),
         ))
     }
     /// Convinience function to create multiple accounts with the same
@@ -96,11 +218,22 @@ impl BaseApp {
         return Ok(())
     }
 
+    /// Run empty blocks, so that begin and end blockers take effect without a tx.
+    pub fn skip_blocks(&self, blocks: u64) -> RunnerResult<()> {
+        for _ in 0..blocks {
+            unsafe {
+                BeginBlock(self.id);
+                EndBlock(self.id);
+            }
+        }
+        Ok(())
+    }
+
     fn create_signed_tx<I>(
         &self,
         msgs: I,
         signer: &SigningAccount,
-        fee: Fee,
+        mut fee: Fee,
     ) -> RunnerResult<Vec<u8>>
     where
         I: IntoIterator<Item = cosmrs::Any>,
@@ -124,6 +257,12 @@ impl BaseApp {
 
             u64::from_be_bytes(buf)
         };
+        if let Some(granter) = signer.fee_granter() {
+            fee.granter = Some(granter.parse().map_err(|e| RunnerError::ExecuteError {
+                msg: format!("invalid fee granter `{}`: {}", granter, e),
+            })?);
+        }
+
         let signer_info = SignerInfo::single_direct(Some(signer.public_key()), seq);
         let auth_info = signer_info.auth_info(fee);
         let sign_doc = tx::SignDoc::new(
@@ -184,26 +323,32 @@ impl BaseApp {
     where
         I: IntoIterator<Item = cosmrs::Any>,
     {
-        match &signer.fee_setting() {
+        let (gas_price, gas_adjustment, max_gas_limit) = match &signer.fee_setting() {
             FeeSetting::Auto {
                 gas_price,
                 gas_adjustment,
-            } => {
-                let gas_info = self.simulate_tx(msgs, signer)?;
-                let gas_limit = ((gas_info.gas_used as f64) * (gas_adjustment)).ceil() as u64;
-
-                let amount = cosmrs::Coin {
-                    denom: self.fee_denom.parse().unwrap(),
-                    amount: (((gas_limit as f64) * gas_price).ceil() as u64)
-                        .into(),
-                };
-
-                Ok(Fee::from_amount_and_gas(amount, gas_limit))
-            }
+            } => (gas_price, gas_adjustment, u64::MAX),
+            FeeSetting::AutoCapped {
+                gas_price,
+                gas_adjustment,
+                max_gas_limit,
+            } => (gas_price, gas_adjustment, *max_gas_limit),
             FeeSetting::Custom { .. } => {
                 panic!("estimate fee is a private function and should never be called when fee_setting is Custom");
             }
-        }
+        };
+
+        let gas_info = self.simulate_tx(msgs, signer)?;
+        let gas_limit = ((gas_info.gas_used as f64) * (gas_adjustment)).ceil() as u64;
+        let gas_limit = gas_limit.min(max_gas_limit);
+
+        let amount = cosmrs::Coin {
+            denom: self.fee_denom.parse().unwrap(),
+            amount: (((gas_limit as f64) * gas_price).ceil() as u64)
+                .into(),
+        };
+
+        Ok(Fee::from_amount_and_gas(amount, gas_limit))
     }
 
     /// Ensure that all execution that happens in `execution` happens in a block
@@ -240,6 +385,125 @@ impl BaseApp {
         }
     }
 
+    /// Enforce minimum gas prices (e.g. `"0.025uaura"`) in the ante handler,
+    /// the same way a validator node does for incoming txs.
+    /// Pass an empty string to stop enforcing them.
+    /// Commits an empty block, so they apply from the next tx on.
+    pub fn set_min_gas_prices(&self, min_gas_prices: &str) -> RunnerResult<()> {
+        redefine_as_go_string!(min_gas_prices);
+        unsafe {
+            let res = SetMinGasPrices(self.id, min_gas_prices);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+        Ok(())
+    }
+
+    /// Mint or burn coins so that `address` holds exactly the given amount
+    /// of each denom in `coins`. Balances of other denoms are left untouched.
+    /// Like `fund_account`, module accounts can be set too.
+    pub fn set_balance(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        let coins_json = coins_to_json(coins)?;
+        redefine_as_go_string!(address, coins_json);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = SetBalance(self.id, address, coins_json);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Overwrite a raw key in a contract's storage. An empty `value` removes the key.
+    pub fn set_contract_state(&self, contract: &str, key: &[u8], value: &[u8]) -> RunnerResult<()> {
+        let base64_key = base64::encode(key);
+        let base64_value = base64::encode(value);
+        redefine_as_go_string!(contract, base64_key, base64_value);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = SetContractState(self.id, contract, base64_key, base64_value);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the sequence of an existing account.
+    pub fn set_account_sequence(&self, address: &str, sequence: u64) -> RunnerResult<()> {
+        redefine_as_go_string!(address);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = SetAccountSequence(self.id, address, sequence);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the admin of a contract, bypassing the current admin. `None` clears the admin.
+    pub fn set_contract_admin(&self, contract: &str, admin: Option<&str>) -> RunnerResult<()> {
+        let admin = admin.unwrap_or_default();
+        redefine_as_go_string!(contract, admin);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = SetContractAdmin(self.id, contract, admin);
+            EndBlock(self.id);
+
+            // returns empty bytes if success
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Call the `sudo` entry point of a contract with a JSON encoded message,
+    /// as a privileged module would. State changes are discarded if the call fails.
+    pub fn wasm_sudo<R>(&self, contract: &str, msg: &[u8]) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        let base64_msg = base64::encode(msg);
+        redefine_as_go_string!(contract, base64_msg);
+
+        unsafe {
+            BeginBlock(self.id);
+            let res = WasmSudo(self.id, contract, base64_msg);
+            EndBlock(self.id);
+
+            let res = RawResult::from_non_null_ptr(res).into_result()?;
+
+            ResponseDeliverTx::decode(res.as_slice())
+                .map_err(DecodeError::ProtoDecodeError)?
+                .try_into()
+        }
+    }
+
+    /// Smart account hooks called by the last executed tx, including a failed one.
+    pub fn hook_invocations(&self) -> RunnerResult<Vec<HookInvocation>> {
+        unsafe {
+            let res = SudoTraces(self.id);
+            let res = RawResult::from_non_null_ptr(res).into_result()?;
+
+            serde_json::from_slice(&res)
+                .map_err(DecodeError::JsonDecodeError)
+                .map_err(RunnerError::DecodeError)
+        }
+    }
+
     /// Get parameter set for a given subspace.
     pub fn get_param_set<P: Message + Default>(
         &self,
@@ -257,6 +521,15 @@ impl BaseApp {
     }
 }
 
+fn coins_to_json(coins: &[Coin]) -> RunnerResult<String> {
+    let mut coins = coins.to_vec();
+
+    // invalid coins if denom are unsorted
+    coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    Ok(serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?)
+}
+
 impl<'a> Runner<'a> for BaseApp {
     fn execute_multiple<M, R>(
         &self,
@@ -283,7 +556,9 @@ impl<'a> Runner<'a> for BaseApp {
                     .collect::<Result<Vec<cosmrs::Any>, RunnerError>>()?;
 
                 let fee = match &signer.fee_setting() {
-                    FeeSetting::Auto { .. } => self.estimate_fee(msgs.clone(), signer)?,
+                    FeeSetting::Auto { .. } | FeeSetting::AutoCapped { .. } => {
+                        self.estimate_fee(msgs.clone(), signer)?
+                    }
                     FeeSetting::Custom { amount, gas_limit } => Fee::from_amount_and_gas(
                         cosmrs::Coin {
                             denom: amount.denom.parse().unwrap(),
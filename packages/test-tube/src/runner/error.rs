@@ -14,6 +14,12 @@ pub enum RunnerError {
 
     #[error("execute error: {}", .msg)]
     ExecuteError { msg: String },
+
+    #[error("gas snapshot error: {}", .msg)]
+    GasSnapshotError { msg: String },
+
+    #[error("artifact error: {}", .msg)]
+    ArtifactError { msg: String },
 }
 
 #[derive(Error, Debug)]
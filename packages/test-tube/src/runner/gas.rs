@@ -0,0 +1,294 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::GasInfo;
+
+use crate::runner::error::RunnerError;
+use crate::runner::result::RunnerResult;
+
+/// Setting this environment variable accepts gas regressions and overwrites the snapshot.
+pub const UPDATE_GAS_SNAPSHOT_ENV: &str = "UPDATE_GAS_SNAPSHOT";
+
+/// Collects `gas_used` per label and compares it against a JSON snapshot file
+/// of `{ label: gas_used }` written by a previous run.
+///
+/// Tests run in parallel, so give each test its own snapshot file.
+#[derive(Debug, PartialEq)]
+pub struct GasRecorder {
+    path: PathBuf,
+    tolerance: f64,
+    fail_on_regression: bool,
+    recorded: RefCell<BTreeMap<String, u64>>,
+}
+
+/// Gas used by a label in this run, against the snapshot if it was there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasDiff {
+    pub label: String,
+    pub snapshot: Option<u64>,
+    pub current: u64,
+}
+
+impl GasDiff {
+    /// Whether gas grew by more than `tolerance`, a fraction of the snapshot value.
+    pub fn is_regression(&self, tolerance: f64) -> bool {
+        match self.snapshot {
+            Some(snapshot) => self.current as f64 > snapshot as f64 * (1.0 + tolerance),
+            None => false,
+        }
+    }
+}
+
+impl GasRecorder {
+    /// Recorder that reports any gas growth against the snapshot at `path` without failing.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        GasRecorder {
+            path: path.into(),
+            tolerance: 0.0,
+            fail_on_regression: false,
+            recorded: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Allowed gas growth before reporting, as a fraction (`0.05` is 5%).
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Return an error from `write_snapshot` instead of only reporting regressions.
+    pub fn fail_on_regression(self, fail_on_regression: bool) -> Self {
+        Self {
+            fail_on_regression,
+            ..self
+        }
+    }
+
+    /// Record gas used under `label`, replacing any value recorded before.
+    pub fn record(&self, label: &str, gas_info: &GasInfo) {
+        self.recorded
+            .borrow_mut()
+            .insert(label.to_string(), gas_info.gas_used);
+    }
+
+    pub fn recorded(&self) -> BTreeMap<String, u64> {
+        self.recorded.borrow().clone()
+    }
+
+    /// Compare recorded gas against the snapshot file, if it exists.
+    pub fn compare(&self) -> RunnerResult<Vec<GasDiff>> {
+        let snapshot = self.read_snapshot()?;
+
+        Ok(self
+            .recorded
+            .borrow()
+            .iter()
+            .map(|(label, current)| GasDiff {
+                label: label.clone(),
+                snapshot: snapshot.get(label).copied(),
+                current: *current,
+            })
+            .collect())
+    }
+
+    /// Compare against the snapshot, report regressions to stderr and write recorded gas
+    /// into the snapshot file. Labels not recorded in this run are kept as they are.
+    ///
+    /// When failing on regression, the snapshot is left untouched unless
+    /// `UPDATE_GAS_SNAPSHOT` is set.
+    pub fn write_snapshot(&self) -> RunnerResult<Vec<GasDiff>> {
+        let diffs = self.compare()?;
+        let regressions: Vec<&GasDiff> = diffs
+            .iter()
+            .filter(|d| d.is_regression(self.tolerance))
+            .collect();
+
+        for d in &regressions {
+            eprintln!(
+                "gas regression `{}`: {} -> {} ({})",
+                d.label,
+                d.snapshot.unwrap_or_default(),
+                d.current,
+                self.path.display()
+            );
+        }
+
+        if self.fail_on_regression
+            && !regressions.is_empty()
+            && std::env::var_os(UPDATE_GAS_SNAPSHOT_ENV).is_none()
+        {
+            return Err(RunnerError::GasSnapshotError {
+                msg: format!(
+                    "{} label(s) exceeded the snapshot, set {} to accept: {}",
+                    regressions.len(),
+                    UPDATE_GAS_SNAPSHOT_ENV,
+                    regressions
+                        .iter()
+                        .map(|d| d.label.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            });
+        }
+
+        let mut snapshot = self.read_snapshot()?;
+        snapshot.extend(self.recorded());
+
+        let json = serde_json::to_string_pretty(&snapshot).map_err(|e| {
+            RunnerError::GasSnapshotError {
+                msg: e.to_string(),
+            }
+        })?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| RunnerError::GasSnapshotError {
+                msg: format!("{}: {}", dir.display(), e),
+            })?;
+        }
+        fs::write(&self.path, json + "\n").map_err(|e| RunnerError::GasSnapshotError {
+            msg: format!("{}: {}", self.path.display(), e),
+        })?;
+
+        Ok(diffs)
+    }
+
+    fn read_snapshot(&self) -> RunnerResult<BTreeMap<String, u64>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let json = fs::read_to_string(&self.path).map_err(|e| RunnerError::GasSnapshotError {
+            msg: format!("{}: {}", self.path.display(), e),
+        })?;
+
+        serde_json::from_str(&json).map_err(|e| RunnerError::GasSnapshotError {
+            msg: format!("{}: {}", self.path.display(), e),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gas(gas_used: u64) -> GasInfo {
+        GasInfo {
+            gas_wanted: 0,
+            gas_used,
+        }
+    }
+
+    fn snapshot_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("test-tube-gas-{}", std::process::id()))
+            .join(format!("{}.json", name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn write_baseline(path: &PathBuf, recorded: &[(&str, u64)]) {
+        let recorder = GasRecorder::new(path);
+        for (label, gas_used) in recorded {
+            recorder.record(label, &gas(*gas_used));
+        }
+        recorder.write_snapshot().unwrap();
+    }
+
+    #[test]
+    fn test_is_regression_within_tolerance() {
+        let diff = GasDiff {
+            label: "execute".to_string(),
+            snapshot: Some(1_000),
+            current: 1_050,
+        };
+
+        assert!(diff.is_regression(0.0));
+        assert!(diff.is_regression(0.04));
+        assert!(!diff.is_regression(0.05));
+        assert!(!diff.is_regression(0.1));
+
+        let less = GasDiff { current: 900, ..diff };
+        assert!(!less.is_regression(0.0));
+    }
+
+    #[test]
+    fn test_first_seen_label() {
+        let path = snapshot_path("first_seen");
+        write_baseline(&path, &[("store", 100)]);
+
+        let recorder = GasRecorder::new(&path).fail_on_regression(true);
+        recorder.record("store", &gas(100));
+        recorder.record("instantiate", &gas(500));
+
+        let diffs = recorder.write_snapshot().unwrap();
+        let instantiate = diffs.iter().find(|d| d.label == "instantiate").unwrap();
+        assert_eq!(instantiate.snapshot, None);
+        assert!(!instantiate.is_regression(0.0));
+
+        // first seen labels are added, the others kept
+        assert_eq!(
+            GasRecorder::new(&path).read_snapshot().unwrap(),
+            BTreeMap::from([("instantiate".to_string(), 500), ("store".to_string(), 100)])
+        );
+    }
+
+    #[test]
+    fn test_compare_with_tolerance() {
+        let path = snapshot_path("tolerance");
+        write_baseline(&path, &[("execute", 1_000)]);
+
+        let recorder = GasRecorder::new(&path)
+            .with_tolerance(0.1)
+            .fail_on_regression(true);
+        recorder.record("execute", &gas(1_080));
+
+        let diffs = recorder.write_snapshot().unwrap();
+        assert_eq!(
+            diffs,
+            vec![GasDiff {
+                label: "execute".to_string(),
+                snapshot: Some(1_000),
+                current: 1_080,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_regression_fails_unless_updating() {
+        let path = snapshot_path("update");
+        write_baseline(&path, &[("execute", 1_000), ("query", 10)]);
+
+        let recorder = GasRecorder::new(&path).fail_on_regression(true);
+        recorder.record("execute", &gas(2_000));
+
+        // the baseline is kept when failing
+        assert!(recorder.write_snapshot().is_err());
+        assert_eq!(recorder.read_snapshot().unwrap()["execute"], 1_000);
+
+        // update mode accepts the regression and overwrites the baseline
+        std::env::set_var(UPDATE_GAS_SNAPSHOT_ENV, "1");
+        let res = recorder.write_snapshot();
+        std::env::remove_var(UPDATE_GAS_SNAPSHOT_ENV);
+
+        assert!(res.unwrap()[0].is_regression(0.0));
+        assert_eq!(
+            recorder.read_snapshot().unwrap(),
+            BTreeMap::from([("execute".to_string(), 2_000), ("query".to_string(), 10)])
+        );
+    }
+
+    #[test]
+    fn test_report_mode_overwrites_baseline() {
+        let path = snapshot_path("report");
+        write_baseline(&path, &[("execute", 1_000)]);
+
+        // not failing on regression, the regression is reported and written
+        let recorder = GasRecorder::new(&path);
+        recorder.record("execute", &gas(1_500));
+
+        let diffs = recorder.write_snapshot().unwrap();
+        assert!(diffs[0].is_regression(0.0));
+        assert_eq!(recorder.read_snapshot().unwrap()["execute"], 1_500);
+    }
+}
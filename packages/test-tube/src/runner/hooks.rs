@@ -0,0 +1,58 @@
+use cosmwasm_std::{Attribute, Binary, Coin, Event};
+use serde::Deserialize;
+
+/// A smart account `PreExecute` or `AfterExecute` sudo call made while running a tx.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HookInvocation {
+    /// `pre_execute` or `after_execute`
+    pub hook: String,
+    /// the smart account, whose contract handled the call
+    pub contract: String,
+    pub msgs: Vec<HookMsg>,
+    pub call_info: CallInfo,
+    pub is_authz: bool,
+    pub gas_used: u64,
+    /// set when the hook succeeded
+    #[serde(default)]
+    pub response: Option<HookResponse>,
+    /// set when the hook failed, which fails the tx
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl HookInvocation {
+    pub fn is_pre_execute(&self) -> bool {
+        self.hook == "pre_execute"
+    }
+
+    pub fn is_after_execute(&self) -> bool {
+        self.hook == "after_execute"
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A tx message as passed to the hooks.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HookMsg {
+    pub type_url: String,
+    pub value: Binary,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallInfo {
+    pub fee: Vec<Coin>,
+    pub gas: u64,
+    pub fee_payer: String,
+    pub fee_granter: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HookResponse {
+    #[serde(default)]
+    pub data: Option<Binary>,
+    pub attributes: Vec<Attribute>,
+    pub events: Vec<Event>,
+}
@@ -0,0 +1,41 @@
+/// Assert that an `ExecuteResponse` has an event of the given type with all given attributes,
+/// printing every event of the response on failure.
+///
+/// ```ignore
+/// assert_event!(res, "wasm", "action" => "pre_execute");
+/// ```
+#[macro_export]
+macro_rules! assert_event {
+    ($res:expr, $ty:expr $(, $key:expr => $value:expr)* $(,)?) => {{
+        let attributes: &[(&str, &str)] = &[$((
+            ::std::convert::AsRef::<str>::as_ref(&$key),
+            ::std::convert::AsRef::<str>::as_ref(&$value),
+        )),*];
+        let res = &$res;
+        if !res.has_event($ty, attributes) {
+            panic!(
+                "expected event `{}` with attributes {:?}, got events:\n{:#?}",
+                $ty, attributes, res.events
+            );
+        }
+    }};
+}
+
+/// Assert that an `ExecuteResponse` has no event of the given type with all given attributes,
+/// printing every event of the response on failure.
+#[macro_export]
+macro_rules! assert_no_event {
+    ($res:expr, $ty:expr $(, $key:expr => $value:expr)* $(,)?) => {{
+        let attributes: &[(&str, &str)] = &[$((
+            ::std::convert::AsRef::<str>::as_ref(&$key),
+            ::std::convert::AsRef::<str>::as_ref(&$value),
+        )),*];
+        let res = &$res;
+        if res.has_event($ty, attributes) {
+            panic!(
+                "unexpected event `{}` with attributes {:?}, got events:\n{:#?}",
+                $ty, attributes, res.events
+            );
+        }
+    }};
+}
@@ -3,6 +3,10 @@ use crate::runner::result::{RunnerExecuteResult, RunnerResult};
 
 pub mod app;
 pub mod error;
+pub mod gas;
+pub mod hooks;
+#[macro_use]
+pub mod macros;
 pub mod result;
 
 pub trait Runner<'a> {
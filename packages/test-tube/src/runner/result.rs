@@ -69,6 +69,57 @@ where
     }
 }
 
+impl<R> ExecuteResponse<R>
+where
+    R: prost::Message + Default,
+{
+    /// Events of the given type, in emitted order.
+    pub fn events_by_type(&self, ty: &str) -> Vec<&Event> {
+        self.events.iter().filter(|e| e.ty == ty).collect()
+    }
+
+    /// First event of the given type that has all of `attributes`.
+    pub fn find_event(&self, ty: &str, attributes: &[(&str, &str)]) -> Option<&Event> {
+        self.events
+            .iter()
+            .find(|e| e.ty == ty && has_attributes(e, attributes))
+    }
+
+    /// Whether an event of the given type has all of `attributes`.
+    pub fn has_event(&self, ty: &str, attributes: &[(&str, &str)]) -> bool {
+        self.find_event(ty, attributes).is_some()
+    }
+
+    /// Values of attribute `key` over all events of the given type.
+    pub fn attribute_values(&self, ty: &str, key: &str) -> Vec<&str> {
+        self.events_by_type(ty)
+            .into_iter()
+            .flat_map(|e| e.attributes.iter())
+            .filter(|a| a.key == key)
+            .map(|a| a.value.as_str())
+            .collect()
+    }
+
+    /// `wasm` and custom `wasm-*` events emitted by `contract`,
+    /// matched by their `_contract_address` attribute.
+    pub fn wasm_events(&self, contract: &str) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| e.ty == "wasm" || e.ty.starts_with("wasm-"))
+            .filter(|e| has_attributes(e, &[("_contract_address", contract)]))
+            .collect()
+    }
+}
+
+fn has_attributes(event: &Event, attributes: &[(&str, &str)]) -> bool {
+    attributes.iter().all(|(key, value)| {
+        event
+            .attributes
+            .iter()
+            .any(|a| a.key == *key && a.value == *value)
+    })
+}
+
 /// `RawResult` facilitates type conversions between Go and Rust,
 ///
 /// Since Go struct could not be exposed via cgo due to limitations on